@@ -516,7 +516,11 @@ where
     ///
     /// The produced block will not be inheriently valid, it must be signed by a block producer.
     /// Block signing is out of the scope of this function and should be done by a separate program.
-    pub fn produce_block(&self, randao_reveal: Signature) -> Option<(BeaconBlock, BeaconState)> {
+    pub fn produce_block(
+        &self,
+        randao_reveal: Signature,
+        graffiti: Hash256,
+    ) -> Option<(BeaconBlock, BeaconState)> {
         debug!("Producing block at slot {}...", self.state.read().slot);
 
         let mut state = self.state.read().clone();
@@ -557,6 +561,7 @@ where
                 custody_responses: vec![],
                 deposits: vec![],
                 exits: vec![],
+                graffiti,
             },
         };
 
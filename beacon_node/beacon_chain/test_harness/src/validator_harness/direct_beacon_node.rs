@@ -4,14 +4,18 @@ use attester::{
 };
 use beacon_chain::BeaconChain;
 use block_producer::{
-    BeaconNode as BeaconBlockNode, BeaconNodeError as BeaconBlockNodeError,
-    PublishOutcome as BlockPublishOutcome,
+    AttestationPublishOutcome as ProducerAttestationPublishOutcome, BeaconNode as BeaconBlockNode,
+    BeaconNodeError as BeaconBlockNodeError, NodeHealth, ProduceOutcome,
+    PublishOutcome as BlockPublishOutcome, ValidatorStatus,
 };
 use db::ClientDB;
 use parking_lot::RwLock;
 use slot_clock::SlotClock;
 use std::sync::Arc;
-use types::{AttestationData, BeaconBlock, FreeAttestation, PublicKey, Signature, Slot};
+use types::{
+    AttestationData, BeaconBlock, FreeAttestation, Hash256, PublicKey, Signature, Slot,
+    SyncCommitteeMessage,
+};
 
 // mod attester;
 // mod producer;
@@ -26,6 +30,7 @@ pub struct DirectBeaconNode<T: ClientDB, U: SlotClock> {
     beacon_chain: Arc<BeaconChain<T, U>>,
     published_blocks: RwLock<Vec<BeaconBlock>>,
     published_attestations: RwLock<Vec<FreeAttestation>>,
+    published_sync_messages: RwLock<Vec<SyncCommitteeMessage>>,
 }
 
 impl<T: ClientDB, U: SlotClock> DirectBeaconNode<T, U> {
@@ -34,6 +39,7 @@ impl<T: ClientDB, U: SlotClock> DirectBeaconNode<T, U> {
             beacon_chain,
             published_blocks: RwLock::new(vec![]),
             published_attestations: RwLock::new(vec![]),
+            published_sync_messages: RwLock::new(vec![]),
         }
     }
 
@@ -67,6 +73,26 @@ impl<T: ClientDB, U: SlotClock> AttesterBeaconNode for DirectBeaconNode<T, U> {
         self.published_attestations.write().push(free_attestation);
         Ok(AttestationPublishOutcome::ValidAttestation)
     }
+
+    /// Returns the root of the present head block of the `BeaconChain`, if it is at `slot`.
+    fn beacon_block_root(&self, slot: Slot) -> Result<Option<Hash256>, NodeError> {
+        let head = self.beacon_chain.head();
+        if head.beacon_block.slot == slot {
+            Ok(Some(head.beacon_block_root))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// A sync committee message is not _actually_ published, it is stored in
+    /// `published_sync_messages`.
+    fn publish_sync_message(
+        &self,
+        sync_message: SyncCommitteeMessage,
+    ) -> Result<AttestationPublishOutcome, NodeError> {
+        self.published_sync_messages.write().push(sync_message);
+        Ok(AttestationPublishOutcome::ValidAttestation)
+    }
 }
 
 impl<T: ClientDB, U: SlotClock> BeaconBlockNode for DirectBeaconNode<T, U> {
@@ -84,21 +110,62 @@ impl<T: ClientDB, U: SlotClock> BeaconBlockNode for DirectBeaconNode<T, U> {
             })
     }
 
+    /// The `BeaconChain` is not connected to a network, so it never has any peers.
+    fn peer_count(&self) -> Result<u32, BeaconBlockNodeError> {
+        Ok(0)
+    }
+
+    /// Returns the epoch length from the `BeaconChain` spec.
+    fn epoch_length(&self) -> Result<Option<u64>, BeaconBlockNodeError> {
+        Ok(Some(self.beacon_chain.spec.epoch_length))
+    }
+
+    /// The `BeaconChain` is not connected to a network, so it is never syncing.
+    fn health(&self) -> Result<NodeHealth, BeaconBlockNodeError> {
+        Ok(NodeHealth {
+            is_syncing: false,
+            head_slot: self.beacon_chain.head().beacon_block.slot,
+        })
+    }
+
+    /// Determines the validators status from the present `BeaconChain` state.
+    fn validator_status(
+        &self,
+        pubkey: &PublicKey,
+    ) -> Result<ValidatorStatus, BeaconBlockNodeError> {
+        let validator_index = match self.beacon_chain.validator_index(pubkey) {
+            Some(validator_index) => validator_index,
+            None => return Ok(ValidatorStatus::Unknown),
+        };
+
+        let state = self.beacon_chain.state.read();
+        let validator = &state.validator_registry[validator_index];
+
+        if validator.is_active_at(state.slot) {
+            Ok(ValidatorStatus::Active)
+        } else if state.slot < validator.activation_slot {
+            Ok(ValidatorStatus::Pending)
+        } else {
+            Ok(ValidatorStatus::Exited)
+        }
+    }
+
     /// Requests a new `BeaconBlock from the `BeaconChain`.
     fn produce_beacon_block(
         &self,
         slot: Slot,
         randao_reveal: &Signature,
-    ) -> Result<Option<BeaconBlock>, BeaconBlockNodeError> {
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconBlockNodeError> {
         let (block, _state) = self
             .beacon_chain
-            .produce_block(randao_reveal.clone())
+            .produce_block(randao_reveal.clone(), Hash256::from(&graffiti[..]))
             .ok_or_else(|| {
                 BeaconBlockNodeError::RemoteFailure(format!("Did not produce block."))
             })?;
 
         if block.slot == slot {
-            Ok(Some(block))
+            Ok(ProduceOutcome::Block(block))
         } else {
             Err(BeaconBlockNodeError::RemoteFailure(
                 "Unable to produce at non-current slot.".to_string(),
@@ -106,6 +173,17 @@ impl<T: ClientDB, U: SlotClock> BeaconBlockNode for DirectBeaconNode<T, U> {
         }
     }
 
+    /// The `BeaconChain` produces blocks synchronously and never times out, so a minimal block is
+    /// simply a regular block.
+    fn produce_minimal_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconBlockNodeError> {
+        self.produce_beacon_block(slot, randao_reveal, graffiti)
+    }
+
     /// A block is not _actually_ published to the `BeaconChain`, instead it is stored in the
     /// `published_block_vec` and a successful `ValidBlock` is returned to the caller.
     ///
@@ -118,4 +196,26 @@ impl<T: ClientDB, U: SlotClock> BeaconBlockNode for DirectBeaconNode<T, U> {
         self.published_blocks.write().push(block);
         Ok(BlockPublishOutcome::ValidBlock)
     }
+
+    /// Requests new `AttestationData` from the `BeaconChain`.
+    fn produce_attestation(
+        &self,
+        _slot: Slot,
+        shard: u64,
+    ) -> Result<Option<AttestationData>, BeaconBlockNodeError> {
+        match self.beacon_chain.produce_attestation_data(shard) {
+            Ok(attestation_data) => Ok(Some(attestation_data)),
+            Err(e) => Err(BeaconBlockNodeError::RemoteFailure(format!("{:?}", e))),
+        }
+    }
+
+    /// An attestation is not _actually_ published to the `BeaconChain`, instead it is stored in
+    /// `published_attestations`.
+    fn publish_attestation(
+        &self,
+        free_attestation: FreeAttestation,
+    ) -> Result<ProducerAttestationPublishOutcome, BeaconBlockNodeError> {
+        self.published_attestations.write().push(free_attestation);
+        Ok(ProducerAttestationPublishOutcome::ValidAttestation)
+    }
 }
@@ -8,7 +8,7 @@ use block_producer::{
 use db::ClientDB;
 use slot_clock::SlotClock;
 use std::sync::Arc;
-use types::{PublicKey, Slot};
+use types::{Epoch, PublicKey, Slot};
 
 /// Connects directly to a borrowed `BeaconChain` and reads attester/proposer duties directly from
 /// it.
@@ -39,6 +39,18 @@ impl<T: ClientDB, U: SlotClock> ProducerDutiesReader for DirectDuties<T, U> {
             Err(_) => Err(ProducerDutiesReaderError::UnknownEpoch),
         }
     }
+
+    /// `DirectDuties` only ever reads the duties of a single validator.
+    fn managed_validator_count(&self) -> Result<usize, ProducerDutiesReaderError> {
+        Ok(1)
+    }
+
+    /// Duties are read directly from the `BeaconChain`, so they are always known for the epoch of
+    /// the present state.
+    fn duties_epoch(&self) -> Result<Option<Epoch>, ProducerDutiesReaderError> {
+        let slot = self.beacon_chain.state.read().slot;
+        Ok(Some(slot.epoch(self.beacon_chain.spec.epoch_length)))
+    }
 }
 
 impl<T: ClientDB, U: SlotClock> AttesterDutiesReader for DirectDuties<T, U> {
@@ -66,4 +78,9 @@ impl<T: ClientDB, U: SlotClock> AttesterDutiesReader for DirectDuties<T, U> {
             Err(AttesterDutiesReaderError::UnknownValidator)
         }
     }
+
+    /// The `BeaconChain` has no sync committees, so there are never any sync committee duties.
+    fn sync_committee_duty(&self, _slot: Slot) -> Result<bool, AttesterDutiesReaderError> {
+        Ok(false)
+    }
 }
@@ -1,5 +1,5 @@
 use attester::Signer as AttesterSigner;
-use block_producer::Signer as BlockProposerSigner;
+use block_producer::{Signer as BlockProposerSigner, SignerError as BlockProposerSignerError};
 use std::sync::RwLock;
 use types::{Keypair, Signature};
 
@@ -31,12 +31,22 @@ impl LocalSigner {
 }
 
 impl BlockProposerSigner for LocalSigner {
-    fn sign_block_proposal(&self, message: &[u8]) -> Option<Signature> {
+    fn sign_block_proposal(&self, message: &[u8]) -> Result<Signature, BlockProposerSignerError> {
         self.bls_sign(message)
+            .ok_or_else(|| BlockProposerSignerError::Backend("Signing failed.".to_string()))
     }
 
-    fn sign_randao_reveal(&self, message: &[u8]) -> Option<Signature> {
+    fn sign_randao_reveal(&self, message: &[u8]) -> Result<Signature, BlockProposerSignerError> {
         self.bls_sign(message)
+            .ok_or_else(|| BlockProposerSignerError::Backend("Signing failed.".to_string()))
+    }
+
+    fn sign_attestation_message(
+        &self,
+        message: &[u8],
+    ) -> Result<Signature, BlockProposerSignerError> {
+        self.bls_sign(message)
+            .ok_or_else(|| BlockProposerSignerError::Backend("Signing failed.".to_string()))
     }
 }
 
@@ -44,4 +54,8 @@ impl AttesterSigner for LocalSigner {
     fn sign_attestation_message(&self, message: &[u8]) -> Option<Signature> {
         self.bls_sign(message)
     }
+
+    fn sign_sync_committee_message(&self, message: &[u8]) -> Option<Signature> {
+        self.bls_sign(message)
+    }
 }
@@ -4,8 +4,9 @@ use super::local_signer::LocalSigner;
 use attester::PollOutcome as AttestationPollOutcome;
 use attester::{Attester, Error as AttestationPollError};
 use beacon_chain::BeaconChain;
+use block_producer::test_utils::null_logger;
 use block_producer::PollOutcome as BlockPollOutcome;
-use block_producer::{BlockProducer, Error as BlockPollError};
+use block_producer::{BlockProducer, Error as BlockPollError, InMemorySlashingProtection};
 use db::MemoryDB;
 use slot_clock::TestingSlotClock;
 use std::sync::Arc;
@@ -34,6 +35,7 @@ pub struct ValidatorHarness {
         DirectBeaconNode<MemoryDB, TestingSlotClock>,
         DirectDuties<MemoryDB, TestingSlotClock>,
         LocalSigner,
+        InMemorySlashingProtection,
     >,
     pub attester: Attester<
         TestingSlotClock,
@@ -71,6 +73,8 @@ impl ValidatorHarness {
             slot_clock.clone(),
             beacon_node.clone(),
             signer.clone(),
+            Arc::new(InMemorySlashingProtection::default()),
+            null_logger(),
         );
 
         let attester = Attester::new(
@@ -99,7 +103,9 @@ impl ValidatorHarness {
         // Using `DirectBeaconNode`, the validator will always return sucessufully if it tries to
         // publish a block.
         match self.block_producer.poll() {
-            Ok(BlockPollOutcome::BlockProduced(_)) => {}
+            Ok(BlockPollOutcome::FirstBlockProduced(_, _))
+            | Ok(BlockPollOutcome::BlockProduced(_, _))
+            | Ok(BlockPollOutcome::BlockProducedMinimal(_, _)) => {}
             Ok(outcome) => return Err(BlockProduceError::DidNotProduce(outcome)),
             Err(error) => return Err(BlockProduceError::PollError(error)),
         };
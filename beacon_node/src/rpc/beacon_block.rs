@@ -26,6 +26,7 @@ impl BeaconBlockService for BeaconBlockServiceInstance {
         let mut block = BeaconBlockProto::new();
         block.set_slot(req.get_slot());
         block.set_block_root("cats".as_bytes().to_vec());
+        block.set_graffiti(req.get_graffiti().to_vec());
 
         let mut resp = ProduceBeaconBlockResponse::new();
         resp.set_block(block);
@@ -1,3 +1,4 @@
+mod sync_committee_producer;
 pub mod test_utils;
 mod traits;
 
@@ -5,6 +6,7 @@ use slot_clock::SlotClock;
 use std::sync::Arc;
 use types::{AttestationData, FreeAttestation, Signature, Slot};
 
+pub use self::sync_committee_producer::{SyncCommitteePollOutcome, SyncCommitteeProducer};
 pub use self::traits::{
     BeaconNode, BeaconNodeError, DutiesReader, DutiesReaderError, PublishOutcome, Signer,
 };
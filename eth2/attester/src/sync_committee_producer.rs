@@ -0,0 +1,229 @@
+use crate::{BeaconNode, DutiesReader, DutiesReaderError, Error, Signer};
+use slot_clock::SlotClock;
+use ssz::{hash, ssz_encode};
+use std::sync::Arc;
+use types::{ChainSpec, Fork, Hash256, Slot, SyncCommitteeMessage};
+
+#[derive(Debug, PartialEq)]
+pub enum SyncCommitteePollOutcome {
+    /// A sync committee message was produced and published.
+    MessageProduced(Slot),
+    /// The validator is not a member of the sync committee at this slot.
+    MessageNotRequired(Slot),
+    /// The Beacon Node was unable to provide a block root for the slot.
+    BeaconNodeUnableToProduceMessage(Slot),
+    /// The duties for the present epoch were not found.
+    ProducerDutiesUnknown(Slot),
+    /// The slot has already been processed, execution was skipped.
+    SlotAlreadyProcessed(Slot),
+    /// The signer failed to sign the message.
+    SignerRejection(Slot),
+    /// The public key for this validator is not an active validator.
+    ValidatorIsUnknown(Slot),
+}
+
+/// A polling state machine which performs sync committee duties, based upon some duties
+/// (`DutiesReader`) and a concept of time (`SlotClock`).
+///
+/// At each slot where the validator is a member of the sync committee, the head block root is
+/// signed with the sync committee domain and published.
+pub struct SyncCommitteeProducer<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> {
+    pub last_processed_slot: Option<Slot>,
+    spec: Arc<ChainSpec>,
+    fork: Fork,
+    duties: Arc<V>,
+    slot_clock: Arc<T>,
+    beacon_node: Arc<U>,
+    signer: Arc<W>,
+}
+
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> SyncCommitteeProducer<T, U, V, W> {
+    /// Returns a new instance where `last_processed_slot == 0`.
+    ///
+    /// The fork is initialized to the genesis fork version described in the `spec`.
+    pub fn new(
+        spec: Arc<ChainSpec>,
+        duties: Arc<V>,
+        slot_clock: Arc<T>,
+        beacon_node: Arc<U>,
+        signer: Arc<W>,
+    ) -> Self {
+        let fork = Fork {
+            pre_fork_version: spec.genesis_fork_version,
+            post_fork_version: spec.genesis_fork_version,
+            fork_slot: spec.genesis_slot,
+        };
+
+        Self {
+            last_processed_slot: None,
+            spec,
+            fork,
+            duties,
+            slot_clock,
+            beacon_node,
+            signer,
+        }
+    }
+
+    /// Set the `Fork` used to determine signature domains.
+    pub fn set_fork(&mut self, fork: Fork) {
+        self.fork = fork;
+    }
+
+    /// Returns the exact root which will be given to the `Signer` when signing the
+    /// `beacon_block_root` at `slot`.
+    ///
+    /// The root commits to the `beacon_block_root` and the `spec.domain_sync_committee` domain
+    /// of the present `Fork`.
+    pub fn signing_root(&self, slot: Slot, beacon_block_root: Hash256) -> Hash256 {
+        let domain = self.fork.get_domain(slot, self.spec.domain_sync_committee);
+
+        let mut message = ssz_encode(&beacon_block_root);
+        message.append(&mut ssz_encode(&domain));
+        Hash256::from(&hash(&message)[..])
+    }
+
+    /// Poll the `BeaconNode` and produce a sync committee message if required.
+    pub fn poll(&mut self) -> Result<SyncCommitteePollOutcome, Error> {
+        let slot = self
+            .slot_clock
+            .present_slot()
+            .map_err(|_| Error::SlotClockError)?
+            .ok_or(Error::SlotUnknowable)?;
+
+        if !self.is_processed_slot(slot) {
+            self.last_processed_slot = Some(slot);
+
+            match self.duties.sync_committee_duty(slot) {
+                Ok(true) => {}
+                Ok(false) => return Ok(SyncCommitteePollOutcome::MessageNotRequired(slot)),
+                Err(DutiesReaderError::UnknownEpoch) => {
+                    return Ok(SyncCommitteePollOutcome::ProducerDutiesUnknown(slot));
+                }
+                Err(DutiesReaderError::UnknownValidator) => {
+                    return Ok(SyncCommitteePollOutcome::ValidatorIsUnknown(slot));
+                }
+                Err(DutiesReaderError::EpochLengthIsZero) => return Err(Error::EpochLengthIsZero),
+                Err(DutiesReaderError::Poisoned) => return Err(Error::EpochMapPoisoned),
+            };
+
+            self.produce_sync_message(slot)
+        } else {
+            Ok(SyncCommitteePollOutcome::SlotAlreadyProcessed(slot))
+        }
+    }
+
+    fn produce_sync_message(&mut self, slot: Slot) -> Result<SyncCommitteePollOutcome, Error> {
+        let beacon_block_root = match self.beacon_node.beacon_block_root(slot)? {
+            Some(root) => root,
+            None => {
+                return Ok(SyncCommitteePollOutcome::BeaconNodeUnableToProduceMessage(
+                    slot,
+                ))
+            }
+        };
+
+        let signature = match self
+            .signer
+            .sign_sync_committee_message(&self.signing_root(slot, beacon_block_root)[..])
+        {
+            Some(signature) => signature,
+            None => return Ok(SyncCommitteePollOutcome::SignerRejection(slot)),
+        };
+
+        let validator_index = match self.duties.validator_index() {
+            Some(validator_index) => validator_index,
+            None => return Ok(SyncCommitteePollOutcome::ValidatorIsUnknown(slot)),
+        };
+
+        let sync_message = SyncCommitteeMessage {
+            slot,
+            beacon_block_root,
+            validator_index,
+            signature,
+        };
+
+        self.beacon_node.publish_sync_message(sync_message)?;
+        Ok(SyncCommitteePollOutcome::MessageProduced(slot))
+    }
+
+    fn is_processed_slot(&self, slot: Slot) -> bool {
+        match self.last_processed_slot {
+            Some(processed_slot) if slot <= processed_slot => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{EpochMap, LocalSigner, SimulatedBeaconNode};
+    use crate::PublishOutcome;
+    use slot_clock::TestingSlotClock;
+    use types::Keypair;
+
+    #[test]
+    pub fn polling() {
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let beacon_node = Arc::new(SimulatedBeaconNode::default());
+        let keypair = Keypair::random();
+        let signer = Arc::new(LocalSigner::new(keypair.clone()));
+
+        let mut duties = EpochMap::new(spec.epoch_length);
+        let sync_slot = Slot::new(100);
+        duties.insert_sync_committee_slot(sync_slot);
+        duties.set_validator_index(Some(2));
+        let duties = Arc::new(duties);
+
+        let mut producer = SyncCommitteeProducer::new(
+            spec.clone(),
+            duties.clone(),
+            slot_clock.clone(),
+            beacon_node.clone(),
+            signer.clone(),
+        );
+
+        // Configure responses from the BeaconNode.
+        let beacon_block_root = Hash256::from(&[42; 32][..]);
+        beacon_node.set_next_block_root_result(Ok(Some(beacon_block_root)));
+        beacon_node.set_next_publish_sync_result(Ok(PublishOutcome::ValidAttestation));
+
+        // One slot before the sync committee slot, the validator is not in the committee...
+        slot_clock.set_slot(sync_slot.as_u64() - 1);
+        assert_eq!(
+            producer.poll(),
+            Ok(SyncCommitteePollOutcome::MessageNotRequired(sync_slot - 1))
+        );
+        assert_eq!(*beacon_node.block_root_input.read().unwrap(), None);
+        assert_eq!(*beacon_node.publish_sync_input.read().unwrap(), None);
+
+        // On the sync committee slot...
+        slot_clock.set_slot(sync_slot.as_u64());
+        assert_eq!(
+            producer.poll(),
+            Ok(SyncCommitteePollOutcome::MessageProduced(sync_slot))
+        );
+
+        let sync_message = beacon_node
+            .publish_sync_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        assert_eq!(sync_message.slot, sync_slot);
+        assert_eq!(sync_message.beacon_block_root, beacon_block_root);
+        assert_eq!(sync_message.validator_index, 2);
+        assert!(sync_message.signature.verify(
+            &producer.signing_root(sync_slot, beacon_block_root)[..],
+            &keypair.pk
+        ));
+
+        // Trying the same slot again...
+        assert_eq!(
+            producer.poll(),
+            Ok(SyncCommitteePollOutcome::SlotAlreadyProcessed(sync_slot))
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{DutiesReader, DutiesReaderError};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use types::{Epoch, Slot};
 
 pub struct EpochMap {
     epoch_length: u64,
     validator_index: Option<u64>,
     map: HashMap<Epoch, (Slot, u64)>,
+    sync_committee_slots: HashSet<Slot>,
 }
 
 impl EpochMap {
@@ -14,6 +15,7 @@ impl EpochMap {
             epoch_length,
             validator_index: None,
             map: HashMap::new(),
+            sync_committee_slots: HashSet::new(),
         }
     }
 
@@ -25,6 +27,10 @@ impl EpochMap {
     pub fn set_validator_index(&mut self, index: Option<u64>) {
         self.validator_index = index;
     }
+
+    pub fn insert_sync_committee_slot(&mut self, slot: Slot) {
+        self.sync_committee_slots.insert(slot);
+    }
 }
 
 impl DutiesReader for EpochMap {
@@ -41,4 +47,8 @@ impl DutiesReader for EpochMap {
     fn validator_index(&self) -> Option<u64> {
         self.validator_index
     }
+
+    fn sync_committee_duty(&self, slot: Slot) -> Result<bool, DutiesReaderError> {
+        Ok(self.sync_committee_slots.contains(&slot))
+    }
 }
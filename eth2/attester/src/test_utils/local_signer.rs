@@ -28,4 +28,8 @@ impl Signer for LocalSigner {
     fn sign_attestation_message(&self, message: &[u8]) -> Option<Signature> {
         Some(Signature::new(message, &self.keypair.sk))
     }
+
+    fn sign_sync_committee_message(&self, message: &[u8]) -> Option<Signature> {
+        Some(Signature::new(message, &self.keypair.sk))
+    }
 }
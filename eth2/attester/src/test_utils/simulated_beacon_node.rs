@@ -1,9 +1,10 @@
 use crate::traits::{BeaconNode, BeaconNodeError, PublishOutcome};
 use std::sync::RwLock;
-use types::{AttestationData, FreeAttestation, Slot};
+use types::{AttestationData, FreeAttestation, Hash256, Slot, SyncCommitteeMessage};
 
 type ProduceResult = Result<Option<AttestationData>, BeaconNodeError>;
 type PublishResult = Result<PublishOutcome, BeaconNodeError>;
+type BlockRootResult = Result<Option<Hash256>, BeaconNodeError>;
 
 /// A test-only struct used to simulate a Beacon Node.
 #[derive(Default)]
@@ -13,6 +14,12 @@ pub struct SimulatedBeaconNode {
 
     pub publish_input: RwLock<Option<FreeAttestation>>,
     pub publish_result: RwLock<Option<PublishResult>>,
+
+    pub block_root_input: RwLock<Option<Slot>>,
+    pub block_root_result: RwLock<Option<BlockRootResult>>,
+
+    pub publish_sync_input: RwLock<Option<SyncCommitteeMessage>>,
+    pub publish_sync_result: RwLock<Option<PublishResult>>,
 }
 
 impl SimulatedBeaconNode {
@@ -23,6 +30,14 @@ impl SimulatedBeaconNode {
     pub fn set_next_publish_result(&self, result: PublishResult) {
         *self.publish_result.write().unwrap() = Some(result);
     }
+
+    pub fn set_next_block_root_result(&self, result: BlockRootResult) {
+        *self.block_root_result.write().unwrap() = Some(result);
+    }
+
+    pub fn set_next_publish_sync_result(&self, result: PublishResult) {
+        *self.publish_sync_result.write().unwrap() = Some(result);
+    }
 }
 
 impl BeaconNode for SimulatedBeaconNode {
@@ -41,4 +56,20 @@ impl BeaconNode for SimulatedBeaconNode {
             None => panic!("TestBeaconNode: publish_result == None"),
         }
     }
+
+    fn beacon_block_root(&self, slot: Slot) -> BlockRootResult {
+        *self.block_root_input.write().unwrap() = Some(slot);
+        match *self.block_root_result.read().unwrap() {
+            Some(ref r) => r.clone(),
+            None => panic!("TestBeaconNode: block_root_result == None"),
+        }
+    }
+
+    fn publish_sync_message(&self, sync_message: SyncCommitteeMessage) -> PublishResult {
+        *self.publish_sync_input.write().unwrap() = Some(sync_message);
+        match *self.publish_sync_result.read().unwrap() {
+            Some(ref r) => r.clone(),
+            None => panic!("TestBeaconNode: publish_sync_result == None"),
+        }
+    }
 }
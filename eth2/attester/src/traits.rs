@@ -1,4 +1,4 @@
-use types::{AttestationData, FreeAttestation, Signature, Slot};
+use types::{AttestationData, FreeAttestation, Hash256, Signature, Slot, SyncCommitteeMessage};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum BeaconNodeError {
@@ -24,6 +24,16 @@ pub trait BeaconNode: Send + Sync {
         &self,
         free_attestation: FreeAttestation,
     ) -> Result<PublishOutcome, BeaconNodeError>;
+
+    /// Requests the root of the head block at `slot`.
+    ///
+    /// Returns `Ok(None)` if the Beacon Node is unable to provide a root for the given slot.
+    fn beacon_block_root(&self, slot: Slot) -> Result<Option<Hash256>, BeaconNodeError>;
+
+    fn publish_sync_message(
+        &self,
+        sync_message: SyncCommitteeMessage,
+    ) -> Result<PublishOutcome, BeaconNodeError>;
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -41,9 +51,13 @@ pub trait DutiesReader: Send + Sync {
 
     /// Returns `Some(shard)` if this slot is an attestation slot. Otherwise, returns `None.`
     fn validator_index(&self) -> Option<u64>;
+
+    /// Returns `true` if the validator is a member of the sync committee at this slot.
+    fn sync_committee_duty(&self, slot: Slot) -> Result<bool, DutiesReaderError>;
 }
 
 /// Signs message using an internally-maintained private key.
 pub trait Signer {
     fn sign_attestation_message(&self, message: &[u8]) -> Option<Signature>;
+    fn sign_sync_committee_message(&self, message: &[u8]) -> Option<Signature>;
 }
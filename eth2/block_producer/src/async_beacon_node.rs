@@ -0,0 +1,163 @@
+use crate::traits::{
+    AttestationPublishOutcome, BeaconNode, BeaconNodeError, NodeHealth, ProduceOutcome,
+    PublishOutcome, ValidatorStatus,
+};
+use async_trait::async_trait;
+use tokio::runtime::Handle;
+use types::{AttestationData, BeaconBlock, FreeAttestation, PublicKey, Signature, Slot};
+
+/// An asynchronous equivalent of `BeaconNode`, for Beacon Node clients which run on a tokio
+/// runtime.
+///
+/// May be used with the synchronous `BlockProducer` via a `BlockingBeaconNode`.
+#[async_trait]
+pub trait AsyncBeaconNode: Send + Sync {
+    /// Requests the proposer nonce (presently named `proposer_slots`).
+    async fn proposer_nonce(&self, pubkey: &PublicKey) -> Result<u64, BeaconNodeError>;
+
+    /// Requests the number of peers the Beacon Node is connected to.
+    async fn peer_count(&self) -> Result<u32, BeaconNodeError>;
+
+    /// Requests the number of slots per epoch used by the Beacon Node.
+    ///
+    /// Returns `Ok(None)` if the Beacon Node does not report its epoch length.
+    async fn epoch_length(&self) -> Result<Option<u64>, BeaconNodeError>;
+
+    /// Requests the activation status of the validator with the given `pubkey`.
+    async fn validator_status(
+        &self,
+        pubkey: &PublicKey,
+    ) -> Result<ValidatorStatus, BeaconNodeError>;
+
+    /// Requests the sync status and head of the Beacon Node.
+    async fn health(&self) -> Result<NodeHealth, BeaconNodeError>;
+
+    /// Request that the node produces a block, including the given `graffiti` in its body.
+    ///
+    /// Returns `Ok(ProduceOutcome::NoBlockAvailable(reason))` if the Beacon Node is unable to
+    /// produce at the given slot.
+    async fn produce_beacon_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError>;
+
+    /// Request that the node produces a minimal block (e.g., one without attestations), which
+    /// should be quicker to assemble than a full block.
+    async fn produce_minimal_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError>;
+
+    /// Request that the node publishes a block.
+    async fn publish_beacon_block(
+        &self,
+        block: BeaconBlock,
+    ) -> Result<PublishOutcome, BeaconNodeError>;
+
+    /// Request that the node produces the `AttestationData` for the given `shard` at `slot`.
+    ///
+    /// Returns `Ok(None)` if the Beacon Node is unable to produce at the given slot.
+    async fn produce_attestation(
+        &self,
+        slot: Slot,
+        shard: u64,
+    ) -> Result<Option<AttestationData>, BeaconNodeError>;
+
+    /// Request that the node publishes a signed attestation.
+    async fn publish_attestation(
+        &self,
+        free_attestation: FreeAttestation,
+    ) -> Result<AttestationPublishOutcome, BeaconNodeError>;
+}
+
+/// Wraps an `AsyncBeaconNode` so it may be used as a (synchronous) `BeaconNode`, blocking the
+/// calling thread on the given tokio runtime whilst each request completes.
+///
+/// Must not be called from a thread which is driving the runtime (e.g., from within an `async`
+/// task), use `AsyncBlockProducer` or `tokio::task::spawn_blocking` instead.
+pub struct BlockingBeaconNode<A: AsyncBeaconNode> {
+    inner: A,
+    handle: Handle,
+}
+
+impl<A: AsyncBeaconNode> BlockingBeaconNode<A> {
+    pub fn new(inner: A, handle: Handle) -> Self {
+        Self { inner, handle }
+    }
+
+    /// Returns the wrapped `AsyncBeaconNode`.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+}
+
+impl<A: AsyncBeaconNode> BeaconNode for BlockingBeaconNode<A> {
+    fn proposer_nonce(&self, pubkey: &PublicKey) -> Result<u64, BeaconNodeError> {
+        self.handle.block_on(self.inner.proposer_nonce(pubkey))
+    }
+
+    fn peer_count(&self) -> Result<u32, BeaconNodeError> {
+        self.handle.block_on(self.inner.peer_count())
+    }
+
+    fn epoch_length(&self) -> Result<Option<u64>, BeaconNodeError> {
+        self.handle.block_on(self.inner.epoch_length())
+    }
+
+    fn validator_status(&self, pubkey: &PublicKey) -> Result<ValidatorStatus, BeaconNodeError> {
+        self.handle.block_on(self.inner.validator_status(pubkey))
+    }
+
+    fn health(&self) -> Result<NodeHealth, BeaconNodeError> {
+        self.handle.block_on(self.inner.health())
+    }
+
+    fn produce_beacon_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError> {
+        self.handle.block_on(
+            self.inner
+                .produce_beacon_block(slot, randao_reveal, graffiti),
+        )
+    }
+
+    fn produce_minimal_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError> {
+        self.handle.block_on(
+            self.inner
+                .produce_minimal_block(slot, randao_reveal, graffiti),
+        )
+    }
+
+    fn publish_beacon_block(&self, block: BeaconBlock) -> Result<PublishOutcome, BeaconNodeError> {
+        self.handle.block_on(self.inner.publish_beacon_block(block))
+    }
+
+    fn produce_attestation(
+        &self,
+        slot: Slot,
+        shard: u64,
+    ) -> Result<Option<AttestationData>, BeaconNodeError> {
+        self.handle
+            .block_on(self.inner.produce_attestation(slot, shard))
+    }
+
+    fn publish_attestation(
+        &self,
+        free_attestation: FreeAttestation,
+    ) -> Result<AttestationPublishOutcome, BeaconNodeError> {
+        self.handle
+            .block_on(self.inner.publish_attestation(free_attestation))
+    }
+}
@@ -0,0 +1,190 @@
+use crate::async_beacon_node::{AsyncBeaconNode, BlockingBeaconNode};
+use crate::{BlockProducer, DutiesReader, Error, PollOutcome, Signer, SlashingProtection};
+use slot_clock::SlotClock;
+use std::sync::{Arc, Mutex};
+
+type InnerProducer<T, A, V, W, X> = BlockProducer<T, BlockingBeaconNode<A>, V, W, X>;
+
+/// Drives a `BlockProducer` (using an `AsyncBeaconNode`) from within a tokio runtime.
+///
+/// Each poll is performed on tokio's blocking thread pool, so requests to the Beacon Node never
+/// block the runtime's reactor.
+pub struct AsyncBlockProducer<
+    T: SlotClock,
+    A: AsyncBeaconNode,
+    V: DutiesReader,
+    W: Signer,
+    X: SlashingProtection,
+> {
+    inner: Arc<Mutex<InnerProducer<T, A, V, W, X>>>,
+}
+
+impl<T, A, V, W, X> AsyncBlockProducer<T, A, V, W, X>
+where
+    T: SlotClock + 'static,
+    A: AsyncBeaconNode + 'static,
+    V: DutiesReader + 'static,
+    W: Signer + Send + Sync + 'static,
+    X: SlashingProtection + 'static,
+{
+    pub fn new(block_producer: InnerProducer<T, A, V, W, X>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(block_producer)),
+        }
+    }
+
+    /// As per `BlockProducer::poll`, without blocking the runtime.
+    pub async fn poll(&self) -> Result<PollOutcome, Error> {
+        let inner = self.inner.clone();
+
+        tokio::task::spawn_blocking(move || {
+            inner
+                .lock()
+                .map_err(|_| Error::BlockProducerPoisoned)?
+                .poll()
+        })
+        .await
+        .expect("BlockProducer poll panicked")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{null_logger, EpochMap, LocalSigner, SimulatedBeaconNode};
+    use crate::traits::{
+        AttestationPublishOutcome, BeaconNode, BeaconNodeError, NodeHealth, ProduceOutcome,
+        PublishOutcome, ValidatorStatus,
+    };
+    use crate::InMemorySlashingProtection;
+    use async_trait::async_trait;
+    use slot_clock::TestingSlotClock;
+    use tokio::runtime::Handle;
+    use types::test_utils::{SeedableRng, TestRandom, XorShiftRng};
+    use types::{
+        AttestationData, BeaconBlock, ChainSpec, FreeAttestation, Keypair, PublicKey, Signature,
+        Slot,
+    };
+
+    /// Serves the responses of a `SimulatedBeaconNode` asynchronously.
+    struct AsyncSimulatedBeaconNode(Arc<SimulatedBeaconNode>);
+
+    #[async_trait]
+    impl AsyncBeaconNode for AsyncSimulatedBeaconNode {
+        async fn proposer_nonce(&self, pubkey: &PublicKey) -> Result<u64, BeaconNodeError> {
+            self.0.proposer_nonce(pubkey)
+        }
+
+        async fn peer_count(&self) -> Result<u32, BeaconNodeError> {
+            self.0.peer_count()
+        }
+
+        async fn epoch_length(&self) -> Result<Option<u64>, BeaconNodeError> {
+            self.0.epoch_length()
+        }
+
+        async fn validator_status(
+            &self,
+            pubkey: &PublicKey,
+        ) -> Result<ValidatorStatus, BeaconNodeError> {
+            self.0.validator_status(pubkey)
+        }
+
+        async fn health(&self) -> Result<NodeHealth, BeaconNodeError> {
+            self.0.health()
+        }
+
+        async fn produce_beacon_block(
+            &self,
+            slot: Slot,
+            randao_reveal: &Signature,
+            graffiti: &[u8; 32],
+        ) -> Result<ProduceOutcome, BeaconNodeError> {
+            self.0.produce_beacon_block(slot, randao_reveal, graffiti)
+        }
+
+        async fn produce_minimal_block(
+            &self,
+            slot: Slot,
+            randao_reveal: &Signature,
+            graffiti: &[u8; 32],
+        ) -> Result<ProduceOutcome, BeaconNodeError> {
+            self.0.produce_minimal_block(slot, randao_reveal, graffiti)
+        }
+
+        async fn publish_beacon_block(
+            &self,
+            block: BeaconBlock,
+        ) -> Result<PublishOutcome, BeaconNodeError> {
+            self.0.publish_beacon_block(block)
+        }
+
+        async fn produce_attestation(
+            &self,
+            slot: Slot,
+            shard: u64,
+        ) -> Result<Option<AttestationData>, BeaconNodeError> {
+            self.0.produce_attestation(slot, shard)
+        }
+
+        async fn publish_attestation(
+            &self,
+            free_attestation: FreeAttestation,
+        ) -> Result<AttestationPublishOutcome, BeaconNodeError> {
+            self.0.publish_attestation(free_attestation)
+        }
+    }
+
+    #[tokio::test]
+    async fn polling() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let simulated_node = Arc::new(SimulatedBeaconNode::default());
+        let beacon_node = Arc::new(BlockingBeaconNode::new(
+            AsyncSimulatedBeaconNode(simulated_node.clone()),
+            Handle::current(),
+        ));
+        let signer = Arc::new(LocalSigner::new(Keypair::random()));
+
+        let mut epoch_map = EpochMap::new(spec.epoch_length);
+        let produce_slot = Slot::new(100);
+        epoch_map
+            .map
+            .insert(produce_slot.epoch(spec.epoch_length), produce_slot);
+
+        let block_producer = AsyncBlockProducer::new(BlockProducer::new(
+            spec.clone(),
+            Keypair::random().pk,
+            Arc::new(epoch_map),
+            slot_clock.clone(),
+            beacon_node,
+            signer,
+            Arc::new(InMemorySlashingProtection::default()),
+            null_logger(),
+        ));
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        simulated_node.set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+        simulated_node.set_next_publish_result(Ok(PublishOutcome::ValidBlock));
+        simulated_node.set_next_validator_status_result(Ok(ValidatorStatus::Active));
+
+        slot_clock.set_slot(produce_slot.as_u64() - 1);
+        assert_eq!(
+            block_producer.poll().await,
+            Ok(PollOutcome::BlockProductionNotRequired(produce_slot - 1))
+        );
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll().await,
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert!(simulated_node.publish_input.read().unwrap().is_some());
+    }
+}
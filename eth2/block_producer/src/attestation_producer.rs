@@ -0,0 +1,392 @@
+use crate::traits::{
+    AttestationPublishOutcome, BeaconNode, DutiesReader, DutiesReaderError, Signer,
+};
+use crate::Error;
+use slog::{crit, info, warn, Logger};
+use slot_clock::SlotClock;
+use std::sync::Arc;
+use types::{AttestationData, ChainSpec, Epoch, FreeAttestation, Slot};
+
+const PHASE_0_CUSTODY_BIT: bool = false;
+
+#[derive(Debug, PartialEq)]
+pub enum AttestationPollOutcome {
+    /// An attestation was produced and published, with the outcome reported by the Beacon Node.
+    AttestationProduced(Slot, AttestationPublishOutcome),
+    /// The validator is not required to attest at this slot.
+    AttestationNotRequired(Slot),
+    /// The attestation data returned by the Beacon Node was a double or surround vote of an
+    /// attestation signed previously, so it was not signed.
+    SlashableAttestationNotProduced(Slot),
+    /// The Beacon Node was unable to produce attestation data at this slot.
+    BeaconNodeUnableToProduceAttestation(Slot),
+    /// The duties for the epoch of this slot are not yet known.
+    ProducerDutiesUnknown(Slot),
+    /// The slot has already been processed, execution was skipped.
+    SlotAlreadyProcessed(Slot),
+    /// The signer refused to sign the attestation.
+    SignerRejection(Slot),
+    /// The validator is unknown to the `DutiesReader`.
+    ValidatorIsUnknown(Slot),
+}
+
+/// A polling state machine which performs attestation duties, based upon some epoch duties
+/// (`DutiesReader`) and a concept of time (`SlotClock`).
+///
+/// The mirror of `BlockProducer` for attestations. Ensures that attestations are not slashable
+/// by recording the source and target epochs of each signed attestation and refusing to sign a
+/// double or surround vote. Signed attestations are only held in memory, so there is no
+/// protection across restarts.
+pub struct AttestationProducer<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> {
+    pub last_processed_slot: Option<Slot>,
+    /// The `(source, target)` epochs of each attestation signed by this validator.
+    signed_votes: Vec<(Epoch, Epoch)>,
+    spec: Arc<ChainSpec>,
+    duties: Arc<V>,
+    slot_clock: Arc<T>,
+    beacon_node: Arc<U>,
+    signer: Arc<W>,
+    log: Logger,
+}
+
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> AttestationProducer<T, U, V, W> {
+    /// Returns a new instance where `last_processed_slot == 0`.
+    pub fn new(
+        spec: Arc<ChainSpec>,
+        duties: Arc<V>,
+        slot_clock: Arc<T>,
+        beacon_node: Arc<U>,
+        signer: Arc<W>,
+        log: Logger,
+    ) -> Self {
+        Self {
+            last_processed_slot: None,
+            signed_votes: vec![],
+            spec,
+            duties,
+            slot_clock,
+            beacon_node,
+            signer,
+            log,
+        }
+    }
+
+    /// "Poll" to see if the validator is required to take any action.
+    ///
+    /// The slot clock will be read and any new actions undertaken.
+    pub fn poll(&mut self) -> Result<AttestationPollOutcome, Error> {
+        let slot = self
+            .slot_clock
+            .present_slot()
+            .map_err(|_| Error::SlotClockError)?
+            .ok_or(Error::SlotUnknowable)?;
+
+        if self.is_processed_slot(slot) {
+            return Ok(AttestationPollOutcome::SlotAlreadyProcessed(slot));
+        }
+        self.last_processed_slot = Some(slot);
+
+        let shard = match self.duties.attestation_shard(slot) {
+            Ok(Some(shard)) => shard,
+            Ok(None) => return Ok(AttestationPollOutcome::AttestationNotRequired(slot)),
+            Err(DutiesReaderError::UnknownEpoch) => {
+                return Ok(AttestationPollOutcome::ProducerDutiesUnknown(slot));
+            }
+            Err(DutiesReaderError::UnknownValidator) => {
+                return Ok(AttestationPollOutcome::ValidatorIsUnknown(slot));
+            }
+            Err(DutiesReaderError::EpochLengthIsZero) => return Err(Error::EpochLengthIsZero),
+            Err(DutiesReaderError::Poisoned) => return Err(Error::EpochMapPoisoned),
+        };
+
+        self.produce_attestation(slot, shard)
+    }
+
+    /// Produce, sign and publish an attestation for `shard` at `slot`.
+    ///
+    /// Assumes that an attestation is required at this slot (does not check the duties).
+    fn produce_attestation(
+        &mut self,
+        slot: Slot,
+        shard: u64,
+    ) -> Result<AttestationPollOutcome, Error> {
+        let validator_index = match self.duties.validator_index() {
+            Some(validator_index) => validator_index,
+            None => return Ok(AttestationPollOutcome::ValidatorIsUnknown(slot)),
+        };
+
+        let attestation_data = match self.beacon_node.produce_attestation(slot, shard)? {
+            Some(attestation_data) => attestation_data,
+            None => {
+                warn!(self.log, "Beacon node unable to produce attestation"; "slot" => slot);
+                return Ok(AttestationPollOutcome::BeaconNodeUnableToProduceAttestation(slot));
+            }
+        };
+
+        if !self.safe_to_produce(&attestation_data) {
+            crit!(self.log, "Slashable attestation not produced"; "slot" => slot);
+            return Ok(AttestationPollOutcome::SlashableAttestationNotProduced(
+                slot,
+            ));
+        }
+
+        self.store_produce(&attestation_data);
+
+        let message = attestation_data.signable_message(PHASE_0_CUSTODY_BIT);
+        let signature = match self.signer.sign_attestation_message(&message[..]) {
+            Ok(signature) => signature,
+            Err(e) => {
+                warn!(self.log, "Signer refused to sign attestation"; "slot" => slot, "error" => format!("{:?}", e));
+                return Ok(AttestationPollOutcome::SignerRejection(slot));
+            }
+        };
+
+        let free_attestation = FreeAttestation {
+            data: attestation_data,
+            signature,
+            validator_index,
+        };
+
+        let outcome = self.beacon_node.publish_attestation(free_attestation)?;
+        info!(self.log, "Attestation produced"; "slot" => slot, "shard" => shard);
+        Ok(AttestationPollOutcome::AttestationProduced(slot, outcome))
+    }
+
+    fn is_processed_slot(&self, slot: Slot) -> bool {
+        match self.last_processed_slot {
+            Some(processed_slot) if slot <= processed_slot => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the `(source, target)` epochs of the vote described by some `attestation_data`.
+    fn vote_epochs(&self, attestation_data: &AttestationData) -> (Epoch, Epoch) {
+        (
+            attestation_data
+                .justified_slot
+                .epoch(self.spec.epoch_length),
+            attestation_data.slot.epoch(self.spec.epoch_length),
+        )
+    }
+
+    /// Returns `true` if signing some `attestation_data` is safe (non-slashable).
+    ///
+    /// An attestation is slashable if it has the same target epoch as a previously signed
+    /// attestation (a double vote), or if either attestation surrounds the other (a surround
+    /// vote).
+    fn safe_to_produce(&self, attestation_data: &AttestationData) -> bool {
+        let (source, target) = self.vote_epochs(attestation_data);
+
+        self.signed_votes
+            .iter()
+            .all(|&(signed_source, signed_target)| {
+                let is_double_vote = target == signed_target;
+                let is_surrounded = signed_source < source && target < signed_target;
+                let is_surrounding = source < signed_source && signed_target < target;
+
+                !(is_double_vote || is_surrounded || is_surrounding)
+            })
+    }
+
+    /// Record that an attestation was produced so that slashable votes may not be made in the
+    /// future.
+    ///
+    /// The attestation is recorded before it is signed.
+    fn store_produce(&mut self, attestation_data: &AttestationData) {
+        let vote = self.vote_epochs(attestation_data);
+        self.signed_votes.push(vote);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{null_logger, EpochMap, LocalSigner, SimulatedBeaconNode};
+    use crate::SignerError;
+    use slot_clock::TestingSlotClock;
+    use types::test_utils::{SeedableRng, TestRandom, XorShiftRng};
+    use types::{Hash256, Keypair};
+
+    type TestAttestationProducer =
+        AttestationProducer<TestingSlotClock, SimulatedBeaconNode, EpochMap, LocalSigner>;
+
+    /// Returns an `AttestationProducer` for a validator with index 2, which is required to
+    /// attest at the first slot of each epoch in `epochs`.
+    fn attestation_producer(
+        epochs: &[u64],
+    ) -> (
+        TestAttestationProducer,
+        Arc<TestingSlotClock>,
+        Arc<SimulatedBeaconNode>,
+        Arc<LocalSigner>,
+    ) {
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let beacon_node = Arc::new(SimulatedBeaconNode::default());
+        let signer = Arc::new(LocalSigner::new(Keypair::random()));
+
+        let mut duties = EpochMap::new(spec.epoch_length);
+        for epoch in epochs {
+            let epoch = Epoch::new(*epoch);
+            duties
+                .attestation_map
+                .insert(epoch, (epoch.start_slot(spec.epoch_length), 12));
+        }
+        duties.validator_index = Some(2);
+
+        beacon_node
+            .set_next_publish_attestation_result(Ok(AttestationPublishOutcome::ValidAttestation));
+
+        let attestation_producer = AttestationProducer::new(
+            spec,
+            Arc::new(duties),
+            slot_clock.clone(),
+            beacon_node.clone(),
+            signer.clone(),
+            null_logger(),
+        );
+
+        (attestation_producer, slot_clock, beacon_node, signer)
+    }
+
+    #[test]
+    pub fn polling() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let (mut attestation_producer, slot_clock, beacon_node, _) = attestation_producer(&[1, 2]);
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let attest_slot = Epoch::new(2).start_slot(epoch_length);
+
+        let mut attestation_data = AttestationData::random_for_test(&mut rng);
+        attestation_data.slot = attest_slot;
+        attestation_data.justified_slot = Epoch::new(1).start_slot(epoch_length);
+        beacon_node.set_next_produce_attestation_result(Ok(Some(attestation_data.clone())));
+
+        // One slot before the attestation slot...
+        slot_clock.set_slot(attest_slot.as_u64() - 1);
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::AttestationNotRequired(
+                attest_slot - 1
+            ))
+        );
+
+        // On the attestation slot...
+        slot_clock.set_slot(attest_slot.as_u64());
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::AttestationProduced(
+                attest_slot,
+                AttestationPublishOutcome::ValidAttestation
+            ))
+        );
+        assert_eq!(
+            *beacon_node.produce_attestation_input.read().unwrap(),
+            Some((attest_slot, 12))
+        );
+        let published = beacon_node
+            .publish_attestation_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        assert_eq!(published.data, attestation_data);
+        assert_eq!(published.validator_index, 2);
+
+        // Trying the same slot again...
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::SlotAlreadyProcessed(attest_slot))
+        );
+
+        // In an epoch without known duties...
+        let slot = Epoch::new(3).start_slot(epoch_length);
+        slot_clock.set_slot(slot.as_u64());
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::ProducerDutiesUnknown(slot))
+        );
+    }
+
+    #[test]
+    pub fn slashable_attestations() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let (mut attestation_producer, slot_clock, beacon_node, _) = attestation_producer(&[3, 4]);
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let epoch_start = |epoch: u64| Epoch::new(epoch).start_slot(epoch_length);
+
+        // A vote from epoch 1 to epoch 4...
+        let mut attestation_data = AttestationData::random_for_test(&mut rng);
+        attestation_data.justified_slot = epoch_start(1);
+        attestation_data.slot = epoch_start(4);
+        beacon_node.set_next_produce_attestation_result(Ok(Some(attestation_data.clone())));
+        slot_clock.set_slot(epoch_start(4).as_u64());
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::AttestationProduced(
+                epoch_start(4),
+                AttestationPublishOutcome::ValidAttestation
+            ))
+        );
+
+        // A second vote for epoch 4 is a double vote...
+        attestation_data.beacon_block_root = Hash256::from(&[42; 32][..]);
+        beacon_node.set_next_produce_attestation_result(Ok(Some(attestation_data.clone())));
+        attestation_producer.last_processed_slot = None;
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::SlashableAttestationNotProduced(
+                epoch_start(4)
+            ))
+        );
+
+        // A vote from epoch 2 to epoch 3 is surrounded by the first vote...
+        attestation_data.justified_slot = epoch_start(2);
+        attestation_data.slot = epoch_start(3);
+        beacon_node.set_next_produce_attestation_result(Ok(Some(attestation_data)));
+        slot_clock.set_slot(epoch_start(3).as_u64());
+        attestation_producer.last_processed_slot = None;
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::SlashableAttestationNotProduced(
+                epoch_start(3)
+            ))
+        );
+    }
+
+    #[test]
+    pub fn signer_rejection() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let (mut attestation_producer, slot_clock, beacon_node, signer) =
+            attestation_producer(&[2]);
+        let attest_slot = Epoch::new(2).start_slot(ChainSpec::foundation().epoch_length);
+
+        let mut attestation_data = AttestationData::random_for_test(&mut rng);
+        attestation_data.slot = attest_slot;
+        beacon_node.set_next_produce_attestation_result(Ok(Some(attestation_data)));
+        signer.set_next_error(Some(SignerError::Locked));
+
+        slot_clock.set_slot(attest_slot.as_u64());
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::SignerRejection(attest_slot))
+        );
+        assert!(beacon_node
+            .publish_attestation_input
+            .read()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    pub fn beacon_node_unable_to_produce() {
+        let (mut attestation_producer, slot_clock, beacon_node, _) = attestation_producer(&[2]);
+        let attest_slot = Epoch::new(2).start_slot(ChainSpec::foundation().epoch_length);
+        beacon_node.set_next_produce_attestation_result(Ok(None));
+
+        slot_clock.set_slot(attest_slot.as_u64());
+        assert_eq!(
+            attestation_producer.poll(),
+            Ok(AttestationPollOutcome::BeaconNodeUnableToProduceAttestation(attest_slot))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::traits::{DutiesReader, DutiesReaderError};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use types::{Epoch, Slot};
+
+/// Wraps a `DutiesReader`, remembering the result of `is_block_production_slot` for each slot of
+/// the present epoch so the (potentially expensive) inner reader is consulted at most once per
+/// slot.
+///
+/// The cache is cleared whenever a slot in a different epoch is requested. Errors are not cached.
+///
+/// Important: changes to the inner reader's duties for the present epoch will not be seen until
+/// the next epoch, unless `invalidate` is called.
+pub struct CachingDutiesReader<V: DutiesReader> {
+    inner: Arc<V>,
+    epoch_length: u64,
+    cache: RwLock<(Option<Epoch>, HashMap<Slot, bool>)>,
+}
+
+impl<V: DutiesReader> CachingDutiesReader<V> {
+    pub fn new(inner: Arc<V>, epoch_length: u64) -> Self {
+        Self {
+            inner,
+            epoch_length,
+            cache: RwLock::new((None, HashMap::new())),
+        }
+    }
+
+    /// Clears all cached results.
+    pub fn invalidate(&self) -> Result<(), DutiesReaderError> {
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|_| DutiesReaderError::Poisoned)?;
+        *cache = (None, HashMap::new());
+        Ok(())
+    }
+}
+
+impl<V: DutiesReader> DutiesReader for CachingDutiesReader<V> {
+    fn is_block_production_slot(&self, slot: Slot) -> Result<bool, DutiesReaderError> {
+        let epoch = slot
+            .checked_epoch(self.epoch_length)
+            .ok_or(DutiesReaderError::EpochLengthIsZero)?;
+
+        {
+            let cache = self.cache.read().map_err(|_| DutiesReaderError::Poisoned)?;
+            if cache.0 == Some(epoch) {
+                if let Some(result) = cache.1.get(&slot) {
+                    return Ok(*result);
+                }
+            }
+        }
+
+        let result = self.inner.is_block_production_slot(slot)?;
+
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|_| DutiesReaderError::Poisoned)?;
+        if cache.0 != Some(epoch) {
+            *cache = (Some(epoch), HashMap::new());
+        }
+        cache.1.insert(slot, result);
+
+        Ok(result)
+    }
+
+    fn managed_validator_count(&self) -> Result<usize, DutiesReaderError> {
+        self.inner.managed_validator_count()
+    }
+
+    fn duties_epoch(&self) -> Result<Option<Epoch>, DutiesReaderError> {
+        self.inner.duties_epoch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::EpochMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts the calls to `is_block_production_slot` on an `EpochMap`.
+    struct CountingReader {
+        epoch_map: EpochMap,
+        calls: AtomicUsize,
+    }
+
+    impl DutiesReader for CountingReader {
+        fn is_block_production_slot(&self, slot: Slot) -> Result<bool, DutiesReaderError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.epoch_map.is_block_production_slot(slot)
+        }
+
+        fn managed_validator_count(&self) -> Result<usize, DutiesReaderError> {
+            self.epoch_map.managed_validator_count()
+        }
+
+        fn duties_epoch(&self) -> Result<Option<Epoch>, DutiesReaderError> {
+            self.epoch_map.duties_epoch()
+        }
+    }
+
+    fn counting_reader(epoch_length: u64, epochs: u64) -> Arc<CountingReader> {
+        let mut epoch_map = EpochMap::new(epoch_length);
+        for epoch in 0..epochs {
+            let epoch = Epoch::new(epoch);
+            epoch_map.map.insert(epoch, epoch.start_slot(epoch_length));
+        }
+        Arc::new(CountingReader {
+            epoch_map,
+            calls: AtomicUsize::new(0),
+        })
+    }
+
+    #[test]
+    fn consults_inner_once_per_slot() {
+        let epoch_length = 8;
+        let inner = counting_reader(epoch_length, 2);
+        let reader = CachingDutiesReader::new(inner.clone(), epoch_length);
+
+        for _ in 0..10 {
+            assert_eq!(reader.is_block_production_slot(Slot::new(0)), Ok(true));
+            assert_eq!(reader.is_block_production_slot(Slot::new(1)), Ok(false));
+        }
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn invalidates_on_new_epoch() {
+        let epoch_length = 8;
+        let inner = counting_reader(epoch_length, 2);
+        let reader = CachingDutiesReader::new(inner.clone(), epoch_length);
+
+        assert_eq!(reader.is_block_production_slot(Slot::new(1)), Ok(false));
+        assert_eq!(reader.is_block_production_slot(Slot::new(8)), Ok(true));
+        assert_eq!(reader.is_block_production_slot(Slot::new(8)), Ok(true));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+
+        // Returning to the previous epoch consults the inner reader again.
+        assert_eq!(reader.is_block_production_slot(Slot::new(1)), Ok(false));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 3);
+
+        reader.invalidate().unwrap();
+        assert_eq!(reader.is_block_production_slot(Slot::new(1)), Ok(false));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn does_not_cache_errors() {
+        let epoch_length = 8;
+        let inner = counting_reader(epoch_length, 1);
+        let reader = CachingDutiesReader::new(inner.clone(), epoch_length);
+
+        for _ in 0..2 {
+            assert_eq!(
+                reader.is_block_production_slot(Slot::new(8)),
+                Err(DutiesReaderError::UnknownEpoch)
+            );
+        }
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn zero_epoch_length() {
+        let inner = counting_reader(8, 1);
+        let reader = CachingDutiesReader::new(inner.clone(), 0);
+
+        assert_eq!(
+            reader.is_block_production_slot(Slot::new(0)),
+            Err(DutiesReaderError::EpochLengthIsZero)
+        );
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 0);
+    }
+}
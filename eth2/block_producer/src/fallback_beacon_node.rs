@@ -0,0 +1,169 @@
+use crate::traits::{
+    AttestationPublishOutcome, BeaconNode, BeaconNodeError, NodeHealth, ProduceOutcome,
+    PublishOutcome, ValidatorStatus,
+};
+use std::sync::Arc;
+use types::{AttestationData, BeaconBlock, FreeAttestation, PublicKey, Signature, Slot};
+
+/// Wraps an ordered list of Beacon Nodes (e.g., a local node followed by a remote backup).
+///
+/// Each request is sent to each node in turn, until one returns `Ok`. If every node fails, the
+/// errors are returned (in order) as `BeaconNodeError::AllNodesFailed`.
+pub struct FallbackBeaconNode {
+    nodes: Vec<Arc<dyn BeaconNode>>,
+}
+
+impl FallbackBeaconNode {
+    pub fn new(nodes: Vec<Arc<dyn BeaconNode>>) -> Self {
+        Self { nodes }
+    }
+
+    /// Returns the first `Ok` result of `request`, trying each node in order.
+    fn first_ok<R, F>(&self, request: F) -> Result<R, BeaconNodeError>
+    where
+        F: Fn(&dyn BeaconNode) -> Result<R, BeaconNodeError>,
+    {
+        let mut errors = Vec::with_capacity(self.nodes.len());
+
+        for node in &self.nodes {
+            match request(node.as_ref()) {
+                Ok(result) => return Ok(result),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        Err(BeaconNodeError::AllNodesFailed(errors))
+    }
+}
+
+impl BeaconNode for FallbackBeaconNode {
+    fn proposer_nonce(&self, pubkey: &PublicKey) -> Result<u64, BeaconNodeError> {
+        self.first_ok(|node| node.proposer_nonce(pubkey))
+    }
+
+    fn peer_count(&self) -> Result<u32, BeaconNodeError> {
+        self.first_ok(|node| node.peer_count())
+    }
+
+    fn epoch_length(&self) -> Result<Option<u64>, BeaconNodeError> {
+        self.first_ok(|node| node.epoch_length())
+    }
+
+    fn validator_status(&self, pubkey: &PublicKey) -> Result<ValidatorStatus, BeaconNodeError> {
+        self.first_ok(|node| node.validator_status(pubkey))
+    }
+
+    fn health(&self) -> Result<NodeHealth, BeaconNodeError> {
+        self.first_ok(|node| node.health())
+    }
+
+    fn produce_beacon_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError> {
+        self.first_ok(|node| node.produce_beacon_block(slot, randao_reveal, graffiti))
+    }
+
+    fn produce_minimal_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError> {
+        self.first_ok(|node| node.produce_minimal_block(slot, randao_reveal, graffiti))
+    }
+
+    fn publish_beacon_block(&self, block: BeaconBlock) -> Result<PublishOutcome, BeaconNodeError> {
+        self.first_ok(|node| node.publish_beacon_block(block.clone()))
+    }
+
+    fn produce_attestation(
+        &self,
+        slot: Slot,
+        shard: u64,
+    ) -> Result<Option<AttestationData>, BeaconNodeError> {
+        self.first_ok(|node| node.produce_attestation(slot, shard))
+    }
+
+    fn publish_attestation(
+        &self,
+        free_attestation: FreeAttestation,
+    ) -> Result<AttestationPublishOutcome, BeaconNodeError> {
+        self.first_ok(|node| node.publish_attestation(free_attestation.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SimulatedBeaconNode;
+    use types::test_utils::{SeedableRng, TestRandom, XorShiftRng};
+    use types::Keypair;
+
+    fn produce(node: &FallbackBeaconNode) -> Result<ProduceOutcome, BeaconNodeError> {
+        let signature = Signature::new(&[42], &Keypair::random().sk);
+        node.produce_beacon_block(Slot::new(1), &signature, &[0; 32])
+    }
+
+    #[test]
+    fn falls_back_to_second_node() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let primary = Arc::new(SimulatedBeaconNode::default());
+        primary.set_next_produce_result(Err(BeaconNodeError::Timeout));
+        let backup = Arc::new(SimulatedBeaconNode::default());
+        let block = BeaconBlock::random_for_test(&mut rng);
+        backup.set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+
+        let node = FallbackBeaconNode::new(vec![primary.clone(), backup.clone()]);
+
+        match produce(&node) {
+            Ok(ProduceOutcome::Block(_)) => {}
+            other => panic!("Expected a block, got {:?}", other),
+        }
+        assert!(primary.produce_input.read().unwrap().is_some());
+        assert!(backup.produce_input.read().unwrap().is_some());
+    }
+
+    #[test]
+    fn does_not_fall_back_on_success() {
+        let primary = Arc::new(SimulatedBeaconNode::default());
+        primary.set_next_peer_count_result(Ok(8));
+        let backup = Arc::new(SimulatedBeaconNode::default());
+
+        let node = FallbackBeaconNode::new(vec![primary.clone(), backup.clone()]);
+
+        // The backup has no result set, so would panic if it were consulted.
+        assert_eq!(node.peer_count(), Ok(8));
+    }
+
+    #[test]
+    fn all_nodes_failed() {
+        let primary = Arc::new(SimulatedBeaconNode::default());
+        primary.set_next_produce_result(Err(BeaconNodeError::Timeout));
+        let backup = Arc::new(SimulatedBeaconNode::default());
+        backup.set_next_produce_result(Err(BeaconNodeError::DecodeFailure));
+
+        let node = FallbackBeaconNode::new(vec![primary.clone(), backup.clone()]);
+
+        assert_eq!(
+            produce(&node),
+            Err(BeaconNodeError::AllNodesFailed(vec![
+                BeaconNodeError::Timeout,
+                BeaconNodeError::DecodeFailure
+            ]))
+        );
+        assert!(!BeaconNodeError::AllNodesFailed(vec![BeaconNodeError::Timeout]).is_retryable());
+        assert!(BeaconNodeError::AllNodesFailed(vec![
+            BeaconNodeError::Timeout,
+            BeaconNodeError::Unavailable("".to_string())
+        ])
+        .is_retryable());
+        assert_eq!(
+            FallbackBeaconNode::new(vec![]).peer_count(),
+            Err(BeaconNodeError::AllNodesFailed(vec![]))
+        );
+    }
+}
@@ -1,19 +1,70 @@
+mod async_beacon_node;
+mod async_block_producer;
+mod attestation_producer;
+mod caching_duties_reader;
+mod fallback_beacon_node;
+mod map_duties_reader;
+mod metrics;
+mod multi_block_producer;
+mod process_lock;
+mod retry_policy;
+mod slashing_protection;
+mod slot_cache;
 pub mod test_utils;
 mod traits;
 
-use slot_clock::SlotClock;
-use ssz::ssz_encode;
+use slog::{crit, debug, error, info, warn, Logger};
+use slot_clock::{SlotClock, TestingSlotClock};
+use ssz::{ssz_encode, Decodable, TreeHash};
+use std::path::Path;
 use std::sync::Arc;
-use types::{BeaconBlock, ChainSpec, PublicKey, Slot};
+use std::thread;
+use std::time::{Duration, Instant};
+use types::{
+    BeaconBlock, ChainSpec, Epoch, Fork, Hash256, ProposalSignedData, PublicKey, Signature, Slot,
+};
 
+pub use self::async_beacon_node::{AsyncBeaconNode, BlockingBeaconNode};
+pub use self::async_block_producer::AsyncBlockProducer;
+pub use self::attestation_producer::{AttestationPollOutcome, AttestationProducer};
+pub use self::caching_duties_reader::CachingDutiesReader;
+pub use self::fallback_beacon_node::FallbackBeaconNode;
+pub use self::map_duties_reader::MapDutiesReader;
+pub use self::metrics::BlockProducerMetrics;
+pub use self::multi_block_producer::MultiBlockProducer;
+pub use self::process_lock::{FileProcessLock, InMemoryProcessLock};
+pub use self::retry_policy::RetryPolicy;
+pub use self::slashing_protection::{
+    FileSlashingProtection, InMemorySlashingProtection, InterchangeAudit, SlotConflict,
+};
+pub use self::slot_cache::SlotCache;
 pub use self::traits::{
-    BeaconNode, BeaconNodeError, DutiesReader, DutiesReaderError, PublishOutcome, Signer,
+    AttestationPublishOutcome, BeaconNode, BeaconNodeError, BlockRelay, BlockRelayError,
+    DutiesReader, DutiesReaderError, NoBlockReason, NodeHealth, ProcessLock, ProcessLockError,
+    ProduceOutcome, PublishOutcome, Signer, SignerError, SlashingProtection,
+    SlashingProtectionError, ValidatorStatus,
 };
 
+/// The number of slots for which verified signatures are remembered.
+const VERIFIED_SIGNATURES_CACHE_SIZE: usize = 16;
+/// The maximum number of missed slots reported by a single `poll_catch_up`.
+const MAX_MISSED_SLOTS_REPORTED: u64 = 64;
+
 #[derive(Debug, PartialEq)]
 pub enum PollOutcome {
-    /// A new block was produced.
-    BlockProduced(Slot),
+    /// The first block since this `BlockProducer` was created was produced and published.
+    ///
+    /// If the first block produced is a minimal block, `BlockProducedMinimal` is returned instead
+    /// and this outcome will not occur.
+    ///
+    /// As with each outcome of a produced block, the Beacon Node's response to the publication is
+    /// included, as the node may have flagged the block as invalid.
+    FirstBlockProduced(Slot, PublishOutcome),
+    /// A new block was produced and published.
+    BlockProduced(Slot, PublishOutcome),
+    /// The Beacon Node timed out producing a full block, so a minimal block was produced and
+    /// published instead.
+    BlockProducedMinimal(Slot, PublishOutcome),
     /// A block was not produced as it would have been slashable.
     SlashableBlockNotProduced(Slot),
     /// The validator duties did not require a block to be produced.
@@ -22,12 +73,105 @@ pub enum PollOutcome {
     ProducerDutiesUnknown(Slot),
     /// The slot has already been processed, execution was skipped.
     SlotAlreadyProcessed(Slot),
-    /// The Beacon Node was unable to produce a block at that slot.
+    /// The slot passed without being polled (e.g., because the process was paused).
+    SlotMissed(Slot),
+    /// As per `SlotMissed`, but the validator was required to produce a block at the slot.
+    ProposalMissed(Slot),
+    /// The Beacon Node was unable to produce a block at that slot, without stating a reason.
     BeaconNodeUnableToProduceBlock(Slot),
+    /// The Beacon Node was unable to produce a block at that slot as it is syncing.
+    BeaconNodeSyncing(Slot),
+    /// The Beacon Node was unable to produce a block at that slot as it does not know the parent
+    /// block.
+    BeaconNodeMissingParent(Slot),
+    /// The Beacon Node returned a block which is obviously invalid (e.g., a zero `state_root`), so
+    /// it was not signed.
+    BeaconNodeReturnedMalformedBlock(Slot),
+    /// The Beacon Node returned a block whose `randao_reveal` differs from the one supplied, so it
+    /// was not signed.
+    ///
+    /// Only this validator can produce its RANDAO reveal, so the block was likely produced for
+    /// another proposer.
+    RandaoRevealMismatch(Slot),
+    /// The signer is locked and refused to sign the message, signing may succeed later.
+    SignerLocked(Slot),
+    /// The signer does not hold the key for this validator.
+    SignerUnknownKey(Slot),
     /// The signer failed to sign the message.
-    SignerRejection(Slot),
+    SignerRejection(Slot, String),
+    /// The signer returned a signature which is not valid for this validator's `pubkey` (e.g., a
+    /// misconfigured keystore or remote signer), so the block was not published.
+    ///
+    /// Only detected whilst `verify_own_signatures` is set.
+    SignatureMismatch(Slot),
     /// The public key for this validator is not an active validator.
     ValidatorIsUnknown(Slot),
+    /// The validator is known, but has not yet been activated.
+    ValidatorPendingActivation(Slot),
+    /// The Beacon Node has fewer peers than `min_peers`, so a block was not produced.
+    InsufficientPeers(Slot),
+    /// The slot is within the configured quiet range, so no action was taken.
+    QuietWindow(Slot),
+    /// The previously signed block at this slot was published again, without being re-signed.
+    Rebroadcast(Slot),
+    /// The Beacon Node rejected the published block as a conflicting block already exists at
+    /// this slot. This indicates another instance may be signing with the same key.
+    PublishedBlockRejectedAsSlashable(Slot),
+}
+
+impl PollOutcome {
+    /// Returns `false` for the routine outcomes of a poll where nothing was required of the
+    /// validator (`BlockProductionNotRequired` and `SlotAlreadyProcessed`), otherwise `true`.
+    pub fn is_actionable(&self) -> bool {
+        match self {
+            PollOutcome::BlockProductionNotRequired(_) | PollOutcome::SlotAlreadyProcessed(_) => {
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+/// A block which has been produced (and recorded with the slashing protection), but not yet
+/// signed.
+pub(crate) struct UnsignedBlock {
+    /// The slot at which the block was requested.
+    pub slot: Slot,
+    pub block: BeaconBlock,
+    /// The root to be signed, as per `BlockProducer::proposal_signing_root`.
+    pub root: Hash256,
+    pub is_minimal: bool,
+}
+
+/// The progress of a poll started by `BlockProducer::start_poll_slot`.
+pub(crate) enum PollStep {
+    /// The poll is complete.
+    Done(Result<PollOutcome, Error>),
+    /// A block must be signed before the poll can be completed by
+    /// `BlockProducer::finish_poll_slot`.
+    Unsigned(UnsignedBlock),
+}
+
+/// Determines where a signed block is published when a `BlockRelay` is set.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RelayPolicy {
+    /// Submit the block to the relay, then publish it to the Beacon Node. A relay failure does
+    /// not prevent publishing to the Beacon Node.
+    RelayThenBeaconNode,
+    /// Submit the block to the relay only. A relay failure is returned as an error.
+    RelayOnly,
+}
+
+/// The wall-clock durations of the requests made to the Beacon Node during a poll.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PollTiming {
+    /// The slot which was polled.
+    pub slot: Slot,
+    /// The duration of the block production request (including any retries, or the request for
+    /// a minimal block), if it was made.
+    pub produce: Option<Duration>,
+    /// The duration of the request to publish the block, if it was made.
+    pub publish: Option<Duration>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -36,28 +180,113 @@ pub enum Error {
     SlotUnknowable,
     EpochMapPoisoned,
     SlotClockPoisoned,
+    BlockProducerPoisoned,
     EpochLengthIsZero,
+    EpochLengthMismatch,
+    NoBlockToRebroadcast,
+    /// Resetting the processed slot was refused, as a block has been signed at or after the
+    /// slot from which it would be reset.
+    ResetWouldBeSlashable,
+    /// The root to be signed for the block produced at this slot differs from the root derived
+    /// from the block's own fields, so it was not signed.
+    ProposalRootMismatch(Slot),
     BeaconNodeError(BeaconNodeError),
+    BlockRelayError(BlockRelayError),
+    ProcessLockError(ProcessLockError),
+    SlashingProtectionError(SlashingProtectionError),
 }
 
 /// A polling state machine which performs block production duties, based upon some epoch duties
 /// (`EpochDutiesMap`) and a concept of time (`SlotClock`).
 ///
-/// Ensures that messages are not slashable.
+/// Ensures that messages are not slashable by consulting (and recording to) some
+/// `SlashingProtection` before signing.
 ///
 /// Relies upon an external service to keep the `EpochDutiesMap` updated.
-pub struct BlockProducer<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> {
+pub struct BlockProducer<
+    T: SlotClock,
+    U: BeaconNode,
+    V: DutiesReader,
+    W: Signer,
+    X: SlashingProtection,
+> {
     pub last_processed_slot: Option<Slot>,
+    /// If `true`, a minimal block will be requested and published if the Beacon Node times out
+    /// whilst producing a full block.
+    pub prefer_minimal_on_timeout: bool,
+    /// If `Some`, blocks will only be produced whilst the Beacon Node has at least this many peers.
+    pub min_peers: Option<u32>,
+    /// If `true`, blocks will only be produced whilst the Beacon Node reports (via its `health`)
+    /// that it is not syncing.
+    pub require_synced: bool,
+    /// If `true`, blocks will only be produced whilst the Beacon Node reports (via its
+    /// `validator_status`) that the validator is active or exited. Not all Beacon Nodes support
+    /// this request.
+    pub check_validator_status: bool,
+    /// If `true`, the proposer nonce is requested from the Beacon Node before producing a block.
+    /// The nonce is cached until a fork changes the fork version. Not all Beacon Nodes support
+    /// this request.
+    pub track_proposer_nonce: bool,
+    /// If `Some`, the duties of a slot are only queried if its epoch is no more than this many
+    /// epochs after the present epoch (as per the slot clock), protecting a slow `DutiesReader`.
+    /// Later slots return `PollOutcome::ProducerDutiesUnknown`.
+    pub max_lookahead: Option<Epoch>,
+    /// If `Some`, requests to the Beacon Node which fail with a retryable error will be retried
+    /// according to this policy.
+    pub retry_policy: Option<RetryPolicy>,
+    /// If `true`, each signature returned by the signer is verified against `pubkey` before it is
+    /// used.
+    pub verify_own_signatures: bool,
+    /// If `Some`, each signed block is submitted to this relay, as per the `relay_policy`.
+    pub relay: Option<Arc<dyn BlockRelay>>,
+    /// Determines whether blocks are also published to the Beacon Node whilst a `relay` is set.
+    pub relay_policy: RelayPolicy,
+    /// If `Some`, the outcome of each poll is counted.
+    pub metrics: Option<Arc<BlockProducerMetrics>>,
+    /// How long before the start of a slot `poll_with_lead` treats the slot as having arrived.
+    pub production_lead: Duration,
+    /// If `true`, a poisoned slot clock is recovered (with a warning) rather than returning
+    /// `Error::SlotClockPoisoned`.
+    pub recover_poisoned: bool,
+    /// If `true`, the root to be signed for each block is checked against a root derived from the
+    /// block's SSZ encoding (as the Beacon Node would derive it), before the block is recorded or
+    /// signed.
+    pub verify_proposal_root: bool,
+    /// If `Some`, called with the `PollTiming` of each poll during which a block was requested
+    /// from the Beacon Node.
+    pub timing_callback: Option<Box<dyn Fn(PollTiming) + Send + Sync>>,
+    poll_timing: PollTiming,
+    verified_signatures: SlotCache<(Hash256, Signature)>,
+    signature_verification_count: u64,
+    slots_since_duties_updated: Option<u64>,
+    has_produced_since_start: bool,
+    last_polled_slot: Option<Slot>,
+    process_lock: Option<Arc<dyn ProcessLock>>,
+    last_signed_block: Option<BeaconBlock>,
+    quiet_range: Option<(Slot, Slot)>,
+    /// The fork version for which the proposer nonce was fetched, and the nonce.
+    nonce_cache: Option<(u64, u64)>,
     pubkey: PublicKey,
+    graffiti: [u8; 32],
     spec: Arc<ChainSpec>,
+    fork: Fork,
     epoch_map: Arc<V>,
     slot_clock: Arc<T>,
     beacon_node: Arc<U>,
     signer: Arc<W>,
+    slashing_protection: Arc<X>,
+    log: Logger,
 }
 
-impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducer<T, U, V, W> {
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection>
+    BlockProducer<T, U, V, W, X>
+{
     /// Returns a new instance where `last_processed_slot == 0`.
+    ///
+    /// The fork is initialized to the fork schedule described in the `spec` (see
+    /// `ChainSpec::fork`), so domains switch to the post-fork version at the fork epoch without
+    /// intervention.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         spec: Arc<ChainSpec>,
         pubkey: PublicKey,
@@ -65,39 +294,424 @@ impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducer<T, U
         slot_clock: Arc<T>,
         beacon_node: Arc<U>,
         signer: Arc<W>,
+        slashing_protection: Arc<X>,
+        log: Logger,
     ) -> Self {
+        let fork = spec.fork();
+
         Self {
             last_processed_slot: None,
+            prefer_minimal_on_timeout: false,
+            min_peers: None,
+            require_synced: false,
+            check_validator_status: false,
+            track_proposer_nonce: false,
+            max_lookahead: None,
+            retry_policy: None,
+            verify_own_signatures: false,
+            relay: None,
+            relay_policy: RelayPolicy::RelayThenBeaconNode,
+            metrics: None,
+            production_lead: Duration::from_secs(0),
+            recover_poisoned: false,
+            verify_proposal_root: false,
+            timing_callback: None,
+            poll_timing: PollTiming::default(),
+            verified_signatures: SlotCache::new(VERIFIED_SIGNATURES_CACHE_SIZE),
+            signature_verification_count: 0,
+            slots_since_duties_updated: None,
+            has_produced_since_start: false,
+            last_polled_slot: None,
+            process_lock: None,
+            last_signed_block: None,
+            quiet_range: None,
+            nonce_cache: None,
             pubkey,
+            graffiti: [0; 32],
+            spec,
+            fork,
+            epoch_map,
+            slot_clock,
+            beacon_node,
+            signer,
+            slashing_protection,
+            log,
+        }
+    }
+
+    /// As per `new`, but first validates the `spec`.
+    ///
+    /// Returns `Error::EpochLengthIsZero` if `spec.epoch_length == 0`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        spec: Arc<ChainSpec>,
+        pubkey: PublicKey,
+        epoch_map: Arc<V>,
+        slot_clock: Arc<T>,
+        beacon_node: Arc<U>,
+        signer: Arc<W>,
+        slashing_protection: Arc<X>,
+        log: Logger,
+    ) -> Result<Self, Error> {
+        if spec.epoch_length == 0 {
+            return Err(Error::EpochLengthIsZero);
+        }
+
+        Ok(Self::new(
             spec,
+            pubkey,
             epoch_map,
             slot_clock,
             beacon_node,
             signer,
+            slashing_protection,
+            log,
+        ))
+    }
+
+    /// Acquires the `process_lock` for this validator, which is held until this instance is
+    /// dropped. Prevents two producers (e.g., in separate processes) from running for the same
+    /// validator.
+    ///
+    /// Returns `Error::ProcessLockError(ProcessLockError::AlreadyHeld)` if another producer holds
+    /// the lock.
+    pub fn with_process_lock(mut self, process_lock: Arc<dyn ProcessLock>) -> Result<Self, Error> {
+        process_lock.acquire(&self.pubkey)?;
+        self.process_lock = Some(process_lock);
+        Ok(self)
+    }
+}
+
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection>
+    BlockProducer<T, U, V, W, X>
+{
+    /// Returns the public key of the validator for which this instance produces blocks.
+    pub fn pubkey(&self) -> &PublicKey {
+        &self.pubkey
+    }
+
+    /// Set the graffiti to be included in the body of each produced block.
+    ///
+    /// The UTF-8 bytes of `graffiti` are truncated (on a character boundary) or zero-padded to 32
+    /// bytes.
+    pub fn set_graffiti(&mut self, graffiti: &str) {
+        let mut len = graffiti.len().min(32);
+        while !graffiti.is_char_boundary(len) {
+            len -= 1;
         }
+
+        self.graffiti = [0; 32];
+        self.graffiti[..len].copy_from_slice(&graffiti.as_bytes()[..len]);
+    }
+
+    /// Returns the graffiti included in the body of each produced block.
+    pub fn graffiti(&self) -> &[u8; 32] {
+        &self.graffiti
+    }
+
+    /// Suppress block production for all slots from `from` to `to` (inclusive), e.g., during
+    /// planned maintenance. Replaces any existing quiet range.
+    pub fn set_quiet_range(&mut self, from: Slot, to: Slot) {
+        self.quiet_range = Some((from, to));
+    }
+
+    /// Remove the quiet range (if any), resuming block production for all slots.
+    pub fn clear_quiet_range(&mut self) {
+        self.quiet_range = None;
+    }
+
+    /// Set the `Fork` used to determine signature domains, overriding the fork schedule of the
+    /// `spec` (e.g., once the Beacon Node announces a fork the `spec` does not describe).
+    pub fn set_fork(&mut self, fork: Fork) {
+        self.fork = fork;
+    }
+
+    /// Returns the most recently fetched proposer nonce, if any.
+    ///
+    /// Only fetched whilst `track_proposer_nonce` is `true`.
+    pub fn proposer_nonce(&self) -> Option<u64> {
+        self.nonce_cache.map(|(_, nonce)| nonce)
+    }
+
+    /// Returns the signature domain for the given `slot` and `domain_type`.
+    ///
+    /// The domain is always derived from the present `Fork`, so domains are recomputed
+    /// immediately once a fork slot is crossed.
+    pub fn domain(&self, slot: Slot, domain_type: u64) -> u64 {
+        self.fork.get_domain(slot, domain_type)
+    }
+
+    /// Returns the exact root which will be given to the `Signer` when signing the RANDAO reveal
+    /// for a block at `slot`.
+    ///
+    /// The root commits to the epoch of the `slot` and the `spec.domain_randao` domain of the
+    /// present `Fork`.
+    pub fn randao_signing_root(&self, slot: Slot) -> Hash256 {
+        let domain = self.domain(slot, self.spec.domain_randao);
+        slot.epoch(self.spec.epoch_length)
+            .randao_signing_root(domain)
+    }
+
+    /// Reads an EIP-3076 slashing-protection interchange file and returns the block proposals by
+    /// this validator which would prevent new proposals, if the interchange were imported.
+    ///
+    /// Proposals which are already protected against are not reported. Importing a proposal at
+    /// slot `N` prevents proposals at all slots up to and including `N`, as reported by the
+    /// `watermark`. This is a dry-run; nothing is imported.
+    pub fn audit_interchange<P: AsRef<Path>>(&self, path: P) -> Result<InterchangeAudit, Error> {
+        Ok(slashing_protection::audit_interchange(
+            path,
+            &self.pubkey,
+            self.slashing_protection.as_ref(),
+        )?)
+    }
+
+    /// Returns the exact root which will be given to the `Signer` when signing the `block`.
+    ///
+    /// The root commits to the `proposal_root` of the block and the `spec.domain_proposal` domain
+    /// of the present `Fork`. Useful for independently auditing a proposal before it is signed
+    /// (e.g., on an air-gapped machine).
+    pub fn proposal_signing_root(&self, block: &BeaconBlock) -> Hash256 {
+        let domain = self.domain(block.slot, self.spec.domain_proposal);
+        block.proposal_signing_root(&self.spec, domain)
     }
 }
 
-impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducer<T, U, V, W> {
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection>
+    BlockProducer<T, U, V, W, X>
+{
     /// "Poll" to see if the validator is required to take any action.
     ///
     /// The slot clock will be read and any new actions undertaken.
     pub fn poll(&mut self) -> Result<PollOutcome, Error> {
-        let slot = self
+        let slot = self.present_slot()?;
+
+        self.poll_slot(slot)
+    }
+
+    /// As per `poll`, but the next slot is polled once the present time is within
+    /// `production_lead` of its start, allowing for network latency whilst publishing.
+    ///
+    /// Within the lead of the next slot, the present slot is polled first if it has not yet been
+    /// polled. Unless a block is produced for the present slot, the next slot is then polled and
+    /// its outcome returned; the outcome of the present slot is only logged.
+    pub fn poll_with_lead(&mut self) -> Result<PollOutcome, Error> {
+        let slot = self.present_slot()?;
+        let duration_into_slot = self
             .slot_clock
-            .present_slot()
+            .present_duration_into_slot()
             .map_err(|_| Error::SlotClockError)?
             .ok_or(Error::SlotUnknowable)?;
 
+        let slot_duration = Duration::from_secs(self.spec.slot_duration);
+        if duration_into_slot + self.production_lead < slot_duration {
+            return self.poll_slot(slot);
+        }
+
+        if self.last_polled_slot.map_or(true, |last| last < slot) {
+            match self.poll_slot(slot) {
+                Ok(outcome @ PollOutcome::FirstBlockProduced(..))
+                | Ok(outcome @ PollOutcome::BlockProduced(..)) => return Ok(outcome),
+                Ok(outcome) => {
+                    debug!(self.log, "Present slot polled within production lead"; "slot" => slot, "outcome" => format!("{:?}", outcome))
+                }
+                Err(e) => {
+                    warn!(self.log, "Failed to poll present slot within production lead"; "slot" => slot, "error" => format!("{:?}", e))
+                }
+            }
+        }
+        self.poll_slot(slot + 1)
+    }
+
+    /// Returns the duration until the start of the next slot, based upon the slot clock's
+    /// position within the present slot and the slot duration described in the `spec`.
+    ///
+    /// Returns `None` if the slot clock cannot be read or genesis has not yet occurred.
+    pub fn duration_to_next_slot(&self) -> Option<Duration> {
+        let duration_into_slot = self.slot_clock.present_duration_into_slot().ok()??;
+
+        Some(
+            Duration::from_secs(self.spec.slot_duration)
+                .checked_sub(duration_into_slot)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// As per `poll`, but also returns an outcome for each slot that was missed since the
+    /// previous poll (e.g., because the process was paused), in ascending slot order.
+    ///
+    /// Blocks are never produced for missed slots (it would be too late and risks a slashable
+    /// proposal), they are reported as `SlotMissed` (or `ProposalMissed`, if a block was required)
+    /// instead. Only the most recent `MAX_MISSED_SLOTS_REPORTED` missed slots are reported. Only
+    /// the present slot is polled.
+    pub fn poll_catch_up(&mut self) -> Result<Vec<PollOutcome>, Error> {
+        let slot = self.present_slot()?;
+
+        let missed = match self.last_polled_slot {
+            Some(last_polled_slot) => slot
+                .as_u64()
+                .saturating_sub(last_polled_slot.as_u64())
+                .saturating_sub(1),
+            None => 0,
+        };
+        if missed > MAX_MISSED_SLOTS_REPORTED {
+            warn!(
+                self.log, "Too many missed slots to report";
+                "slot" => slot, "missed" => missed, "reported" => MAX_MISSED_SLOTS_REPORTED
+            );
+        }
+
+        let first_reported = slot.as_u64() - missed.min(MAX_MISSED_SLOTS_REPORTED);
+        let mut outcomes: Vec<PollOutcome> = (first_reported..slot.as_u64())
+            .map(|missed_slot| self.missed_slot_outcome(Slot::new(missed_slot)))
+            .collect();
+        outcomes.push(self.poll_slot(slot)?);
+
+        Ok(outcomes)
+    }
+
+    /// Returns (and counts with the `metrics`, if any) the outcome of a `slot` which passed
+    /// without being polled.
+    fn missed_slot_outcome(&self, slot: Slot) -> PollOutcome {
+        let outcome = match self.epoch_map.is_block_production_slot(slot) {
+            Ok(true) => {
+                error!(self.log, "Block proposal missed"; "slot" => slot);
+                PollOutcome::ProposalMissed(slot)
+            }
+            // If the duties are unknown it is too late to learn them, so the slot is reported as
+            // missed without a proposal.
+            Ok(false) | Err(_) => PollOutcome::SlotMissed(slot),
+        };
+
+        if let Some(metrics) = &self.metrics {
+            metrics.observe(&outcome);
+        }
+
+        outcome
+    }
+
+    /// Reads the present slot from the slot clock, recovering it if it is poisoned and
+    /// `recover_poisoned` is set.
+    fn present_slot(&self) -> Result<Slot, Error> {
+        let slot = match self.slot_clock.present_slot() {
+            Ok(slot) => slot,
+            Err(ref e) if T::is_poisoned(e) => {
+                if !self.recover_poisoned {
+                    return Err(Error::SlotClockPoisoned);
+                }
+
+                warn!(self.log, "Slot clock poisoned, recovering");
+                self.slot_clock
+                    .present_slot_recovering()
+                    .map_err(|_| Error::SlotClockError)?
+            }
+            Err(_) => return Err(Error::SlotClockError),
+        };
+
+        slot.ok_or(Error::SlotUnknowable)
+    }
+
+    /// Undertake any new actions required at the given `slot`, without reading the slot clock.
+    ///
+    /// The outcome is counted by the `metrics` (if any).
+    pub fn poll_slot(&mut self, slot: Slot) -> Result<PollOutcome, Error> {
+        match self.start_poll_slot(slot) {
+            PollStep::Done(result) => result,
+            PollStep::Unsigned(unsigned) => {
+                let signature = self
+                    .signer
+                    .sign_block_proposal_for(&self.pubkey, &unsigned.root[..]);
+                self.finish_poll_slot(unsigned, signature)
+            }
+        }
+    }
+
+    /// As per `poll_slot`, except that a block which is ready to be signed is returned (as
+    /// `PollStep::Unsigned`) instead of being signed, so that it may be signed in a batch with
+    /// the blocks of other validators. The poll must then be completed by `finish_poll_slot`.
+    pub(crate) fn start_poll_slot(&mut self, slot: Slot) -> PollStep {
+        if self.last_polled_slot.map_or(true, |last| slot > last) {
+            self.last_polled_slot = Some(slot);
+        }
+        self.poll_timing = PollTiming {
+            slot,
+            ..PollTiming::default()
+        };
+
+        let result = self.start_slot(slot).and_then(|started| match started {
+            Ok(()) => self.produce_unsigned_block(slot),
+            Err(outcome) => Ok(Err(outcome)),
+        });
+
+        match result {
+            Ok(Ok(unsigned)) => PollStep::Unsigned(unsigned),
+            Ok(Err(outcome)) => PollStep::Done(self.observe(Ok(outcome))),
+            Err(e) => PollStep::Done(self.observe(Err(e))),
+        }
+    }
+
+    /// Completes a poll started by `start_poll_slot`, applying the `signature` to the `unsigned`
+    /// block and publishing it.
+    pub(crate) fn finish_poll_slot(
+        &mut self,
+        unsigned: UnsignedBlock,
+        signature: Result<Signature, SignerError>,
+    ) -> Result<PollOutcome, Error> {
+        let slot = unsigned.slot;
+        let produced = self.apply_signature(unsigned, signature);
+        let result = self.publish_produced(slot, produced);
+
+        self.observe(result)
+    }
+
+    /// Counts the outcome of a poll with the `metrics` (if any) and reports its timing to the
+    /// `timing_callback` (if any).
+    fn observe(&self, result: Result<PollOutcome, Error>) -> Result<PollOutcome, Error> {
+        if let (Some(metrics), Ok(outcome)) = (&self.metrics, &result) {
+            metrics.observe(outcome);
+        }
+
+        if let Some(timing_callback) = &self.timing_callback {
+            if self.poll_timing.produce.is_some() {
+                timing_callback(self.poll_timing);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the signer used by this validator.
+    pub(crate) fn signer(&self) -> &Arc<W> {
+        &self.signer
+    }
+
+    /// Determines whether a block is required at `slot`, marking the slot as processed if so.
+    ///
+    /// If a block is not required, the outcome is returned inside `Ok`.
+    fn start_slot(&mut self, slot: Slot) -> Result<Result<(), PollOutcome>, Error> {
         // If this is a new slot.
         if !self.is_processed_slot(slot) {
+            self.check_epoch_length()?;
+
+            if self.is_beyond_lookahead(slot)? {
+                warn!(self.log, "Block production duties beyond lookahead"; "slot" => slot);
+                return Ok(Err(PollOutcome::ProducerDutiesUnknown(slot)));
+            }
+
+            self.update_slots_since_duties_updated(slot)?;
+
+            if self.is_quiet_slot(slot) {
+                return Ok(Err(PollOutcome::QuietWindow(slot)));
+            }
+
             let is_block_production_slot = match self.epoch_map.is_block_production_slot(slot) {
                 Ok(result) => result,
                 Err(DutiesReaderError::UnknownEpoch) => {
-                    return Ok(PollOutcome::ProducerDutiesUnknown(slot));
+                    warn!(self.log, "Block production duties unknown"; "slot" => slot);
+                    return Ok(Err(PollOutcome::ProducerDutiesUnknown(slot)));
                 }
                 Err(DutiesReaderError::UnknownValidator) => {
-                    return Ok(PollOutcome::ValidatorIsUnknown(slot));
+                    return Ok(Err(PollOutcome::ValidatorIsUnknown(slot)));
                 }
                 Err(DutiesReaderError::EpochLengthIsZero) => return Err(Error::EpochLengthIsZero),
                 Err(DutiesReaderError::Poisoned) => return Err(Error::EpochMapPoisoned),
@@ -106,13 +720,102 @@ impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducer<T, U
             if is_block_production_slot {
                 self.last_processed_slot = Some(slot);
 
-                self.produce_block(slot)
+                Ok(Ok(()))
             } else {
-                Ok(PollOutcome::BlockProductionNotRequired(slot))
+                debug!(self.log, "Block production not required"; "slot" => slot);
+                Ok(Err(PollOutcome::BlockProductionNotRequired(slot)))
             }
         } else {
-            Ok(PollOutcome::SlotAlreadyProcessed(slot))
+            Ok(Err(PollOutcome::SlotAlreadyProcessed(slot)))
+        }
+    }
+
+    /// Returns an error if the epoch length of the `spec` differs from that assumed by the slot
+    /// clock or reported by the Beacon Node.
+    ///
+    /// Either may decline to state an epoch length, in which case it is not checked.
+    pub fn check_epoch_length(&self) -> Result<(), Error> {
+        if let Some(epoch_length) = self.slot_clock.epoch_length() {
+            if epoch_length != self.spec.epoch_length {
+                return Err(Error::EpochLengthMismatch);
+            }
+        }
+
+        if let Some(epoch_length) = self.beacon_node.epoch_length()? {
+            if epoch_length != self.spec.epoch_length {
+                return Err(Error::EpochLengthMismatch);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of slots between the first slot of the latest epoch for which duties
+    /// are known and the most recently polled slot.
+    ///
+    /// Whilst duties are kept up-to-date this is less than the epoch length, a larger value
+    /// indicates that the duties are no longer being updated. Returns `None` if no slot has been
+    /// polled or no duties are known.
+    pub fn slots_since_duties_updated(&self) -> Option<u64> {
+        self.slots_since_duties_updated
+    }
+
+    fn update_slots_since_duties_updated(&mut self, slot: Slot) -> Result<(), Error> {
+        let duties_epoch = match self.epoch_map.duties_epoch() {
+            Ok(duties_epoch) => duties_epoch,
+            Err(DutiesReaderError::Poisoned) => return Err(Error::EpochMapPoisoned),
+            Err(_) => None,
+        };
+
+        self.slots_since_duties_updated = duties_epoch.map(|epoch| {
+            let start_slot = epoch.start_slot(self.spec.epoch_length);
+            slot.as_u64().saturating_sub(start_slot.as_u64())
+        });
+
+        Ok(())
+    }
+
+    /// Returns `true` if the epoch of `slot` is more than `max_lookahead` epochs after the present
+    /// epoch. Only reads the slot clock if `max_lookahead` is set.
+    fn is_beyond_lookahead(&self, slot: Slot) -> Result<bool, Error> {
+        match self.max_lookahead {
+            Some(max_lookahead) => {
+                let present_epoch = self.present_slot()?.epoch(self.spec.epoch_length);
+                Ok(slot.epoch(self.spec.epoch_length) > present_epoch + max_lookahead)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn is_quiet_slot(&self, slot: Slot) -> bool {
+        match self.quiet_range {
+            Some((from, to)) => slot >= from && slot <= to,
+            None => false,
+        }
+    }
+
+    /// Allows each slot from `before` onwards to be processed again (e.g., once an operator has
+    /// confirmed that no block was signed during some window).
+    ///
+    /// Returns `Error::ResetWouldBeSlashable` if the `slashing_protection` records a block signed
+    /// at or after `before`, leaving `last_processed_slot` unchanged.
+    pub fn reset_processed_slot(&mut self, before: Slot) -> Result<(), Error> {
+        if self
+            .slashing_protection
+            .is_slashable_block(&self.pubkey, before)
+        {
+            return Err(Error::ResetWouldBeSlashable);
+        }
+
+        if self.is_processed_slot(before) {
+            self.last_processed_slot = if before.is_genesis() {
+                None
+            } else {
+                Some(before - 1)
+            };
         }
+
+        Ok(())
     }
 
     fn is_processed_slot(&self, slot: Slot) -> bool {
@@ -122,104 +825,495 @@ impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducer<T, U
         }
     }
 
-    /// Produce a block at some slot.
+    /// Publishes a block `produced` (and signed) at some slot, returning the outcome.
     ///
-    /// Assumes that a block is required at this slot (does not check the duties).
+    /// If a signed block was not produced, the reason is returned.
+    fn publish_produced(
+        &mut self,
+        slot: Slot,
+        produced: Result<(BeaconBlock, bool), PollOutcome>,
+    ) -> Result<PollOutcome, Error> {
+        let (block, is_minimal) = match produced {
+            Ok(produced) => produced,
+            Err(outcome) => return Ok(outcome),
+        };
+
+        self.last_signed_block = Some(block.clone());
+        let publish_start = Instant::now();
+        let publish_outcome = self.publish(block);
+        self.poll_timing.publish = Some(publish_start.elapsed());
+        let publish_outcome = publish_outcome?;
+        match &publish_outcome {
+            PublishOutcome::RejectedSlashable => {
+                // The slot was recorded when the block was signed, so it will not be signed again.
+                crit!(self.log, "Published block rejected as slashable"; "slot" => slot);
+                return Ok(PollOutcome::PublishedBlockRejectedAsSlashable(slot));
+            }
+            PublishOutcome::InvalidBlock(reason) => {
+                warn!(self.log, "Published block flagged invalid"; "slot" => slot, "reason" => reason)
+            }
+            PublishOutcome::ValidBlock => {}
+        }
+        let is_first = !self.has_produced_since_start;
+        self.has_produced_since_start = true;
+        info!(
+            self.log, "Block produced";
+            "slot" => slot, "minimal" => is_minimal, "first" => is_first
+        );
+
+        if is_minimal {
+            Ok(PollOutcome::BlockProducedMinimal(slot, publish_outcome))
+        } else if is_first {
+            Ok(PollOutcome::FirstBlockProduced(slot, publish_outcome))
+        } else {
+            Ok(PollOutcome::BlockProduced(slot, publish_outcome))
+        }
+    }
+
+    /// Produce and sign (but do not publish) a single block at `slot`, e.g., for a manually
+    /// triggered proposal.
     ///
-    /// Ensures the message is not slashable.
+    /// Does not check the duties, but the block is only signed if it is not slashable (and the
+    /// proposal is recorded as such). Returns `None` if a signed block was not produced.
+    pub fn produce_once(&mut self, slot: Slot) -> Result<Option<BeaconBlock>, Error> {
+        Ok(self
+            .produce_signed_block(slot)?
+            .ok()
+            .map(|(block, _is_minimal)| block))
+    }
+
+    /// Produce and sign a block at `slot`, returning the block and whether it is a minimal block.
     ///
-    /// !!! UNSAFE !!!
+    /// If a signed block could not be produced, the reason is returned as a `PollOutcome` inside
+    /// `Ok`.
+    fn produce_signed_block(
+        &mut self,
+        slot: Slot,
+    ) -> Result<Result<(BeaconBlock, bool), PollOutcome>, Error> {
+        match self.produce_unsigned_block(slot)? {
+            Ok(unsigned) => {
+                let signature = self
+                    .signer
+                    .sign_block_proposal_for(&self.pubkey, &unsigned.root[..]);
+                Ok(self.apply_signature(unsigned, signature))
+            }
+            Err(outcome) => Ok(Err(outcome)),
+        }
+    }
+
+    /// Produce a block at `slot` which is ready to be signed, recording it with the slashing
+    /// protection.
     ///
-    /// The slash-protection code is not yet implemented. There is zero protection against
-    /// slashing.
-    fn produce_block(&mut self, slot: Slot) -> Result<PollOutcome, Error> {
-        let randao_reveal = {
-            let producer_nonce = self.beacon_node.proposer_nonce(&self.pubkey)?;
+    /// Assumes that a block is required at this slot (does not check the duties). The block is
+    /// only returned if it is not slashable, otherwise the reason a block could not be produced
+    /// is returned as a `PollOutcome` inside `Ok`.
+    fn produce_unsigned_block(
+        &mut self,
+        slot: Slot,
+    ) -> Result<Result<UnsignedBlock, PollOutcome>, Error> {
+        if let Some(min_peers) = self.min_peers {
+            if self.beacon_node.peer_count()? < min_peers {
+                return Ok(Err(PollOutcome::InsufficientPeers(slot)));
+            }
+        }
+
+        if self.require_synced && self.beacon_node.health()?.is_syncing {
+            warn!(self.log, "Beacon node is syncing, unable to produce block"; "slot" => slot);
+            return Ok(Err(PollOutcome::BeaconNodeSyncing(slot)));
+        }
+
+        if self.check_validator_status {
+            match self.with_retries(|| self.beacon_node.validator_status(&self.pubkey))? {
+                ValidatorStatus::Unknown => return Ok(Err(PollOutcome::ValidatorIsUnknown(slot))),
+                ValidatorStatus::Pending => {
+                    return Ok(Err(PollOutcome::ValidatorPendingActivation(slot)));
+                }
+                ValidatorStatus::Active | ValidatorStatus::Exited => {}
+            };
+        }
+
+        if self.track_proposer_nonce {
+            self.refresh_proposer_nonce(slot)?;
+        }
 
-            // TODO: add domain, etc to this message.
-            let message = ssz_encode(&producer_nonce);
+        let randao_reveal = {
+            let message = self.randao_signing_root(slot);
 
-            match self.signer.sign_randao_reveal(&message) {
-                None => return Ok(PollOutcome::SignerRejection(slot)),
-                Some(signature) => signature,
+            match self
+                .signer
+                .sign_randao_reveal_for(&self.pubkey, &message[..])
+            {
+                Err(e) => return Ok(Err(signer_rejection(&self.log, slot, e))),
+                Ok(signature) => {
+                    if self.verify_own_signatures
+                        && !self.verify_own_signature(slot, message, &signature)
+                    {
+                        return Ok(Err(signature_mismatch(&self.log, slot)));
+                    }
+                    signature
+                }
             }
         };
 
-        if let Some(block) = self
-            .beacon_node
-            .produce_beacon_block(slot, &randao_reveal)?
-        {
-            if self.safe_to_produce(&block) {
-                if let Some(block) = self.sign_block(block) {
-                    self.beacon_node.publish_beacon_block(block)?;
-                    Ok(PollOutcome::BlockProduced(slot))
+        let produce_start = Instant::now();
+        let produced = match self.with_retries(|| {
+            self.beacon_node
+                .produce_beacon_block(slot, &randao_reveal, &self.graffiti)
+        }) {
+            Err(BeaconNodeError::Timeout) if self.prefer_minimal_on_timeout => self
+                .with_retries(|| {
+                    self.beacon_node
+                        .produce_minimal_block(slot, &randao_reveal, &self.graffiti)
+                })
+                .map(|produced| (produced, true)),
+            result => result.map(|produced| (produced, false)),
+        };
+        self.poll_timing.produce = Some(produce_start.elapsed());
+        let (produced, is_minimal) = produced?;
+
+        match produced {
+            ProduceOutcome::Block(block) => {
+                if block.state_root == Hash256::zero() {
+                    error!(self.log, "Beacon node returned a malformed block"; "slot" => slot);
+                    Ok(Err(PollOutcome::BeaconNodeReturnedMalformedBlock(slot)))
+                } else if block.slot != slot {
+                    error!(self.log, "Beacon node returned a block for another slot"; "slot" => slot, "block_slot" => block.slot);
+                    Ok(Err(PollOutcome::BeaconNodeReturnedMalformedBlock(slot)))
+                } else if block.randao_reveal != randao_reveal {
+                    error!(self.log, "Beacon node returned a block with another RANDAO reveal"; "slot" => slot);
+                    Ok(Err(PollOutcome::RandaoRevealMismatch(slot)))
+                } else if self.safe_to_produce(&block) {
+                    Ok(Ok(self.prepare_signing(slot, block, is_minimal)?))
                 } else {
-                    Ok(PollOutcome::SignerRejection(slot))
+                    crit!(self.log, "Slashable block not produced"; "slot" => slot);
+                    Ok(Err(PollOutcome::SlashableBlockNotProduced(slot)))
                 }
-            } else {
-                Ok(PollOutcome::SlashableBlockNotProduced(slot))
             }
-        } else {
-            Ok(PollOutcome::BeaconNodeUnableToProduceBlock(slot))
+            ProduceOutcome::NoBlockAvailable(NoBlockReason::Syncing) => {
+                warn!(self.log, "Beacon node is syncing, unable to produce block"; "slot" => slot);
+                Ok(Err(PollOutcome::BeaconNodeSyncing(slot)))
+            }
+            ProduceOutcome::NoBlockAvailable(NoBlockReason::MissingParent) => {
+                warn!(self.log, "Beacon node is missing the parent block"; "slot" => slot);
+                Ok(Err(PollOutcome::BeaconNodeMissingParent(slot)))
+            }
+            ProduceOutcome::NoBlockAvailable(NoBlockReason::Unspecified) => {
+                error!(self.log, "Beacon node unable to produce block"; "slot" => slot);
+                Ok(Err(PollOutcome::BeaconNodeUnableToProduceBlock(slot)))
+            }
         }
     }
 
-    /// Consumes a block, returning that block signed by the validators private key.
+    /// Publishes the most recently published block again, e.g., if it did not propagate due to a
+    /// lack of peers.
     ///
-    /// Important: this function will not check to ensure the block is not slashable. This must be
-    /// done upstream.
-    fn sign_block(&mut self, mut block: BeaconBlock) -> Option<BeaconBlock> {
-        self.store_produce(&block);
+    /// The block is not re-signed, so this is never slashable. Returns an error if no block has
+    /// been published since this `BlockProducer` was created. Blocks from `produce_once` are not
+    /// published, so they are never rebroadcast.
+    pub fn rebroadcast_last(&mut self) -> Result<PollOutcome, Error> {
+        let block = self
+            .last_signed_block
+            .clone()
+            .ok_or(Error::NoBlockToRebroadcast)?;
+        let slot = block.slot;
 
-        match self
-            .signer
-            .sign_block_proposal(&block.proposal_root(&self.spec)[..])
-        {
-            None => None,
-            Some(signature) => {
-                block.signature = signature;
-                Some(block)
-            }
+        self.publish(block)?;
+
+        let outcome = PollOutcome::Rebroadcast(slot);
+        if let Some(metrics) = &self.metrics {
+            metrics.observe(&outcome);
         }
+        Ok(outcome)
     }
 
-    /// Returns `true` if signing a block is safe (non-slashable).
+    /// Publishes a signed block to the `relay` (if any) and/or the Beacon Node, as per the
+    /// `relay_policy`.
     ///
-    /// !!! UNSAFE !!!
+    /// Returns the outcome reported by the Beacon Node. A relay does not report an outcome, so
+    /// `PublishOutcome::ValidBlock` is returned when using `RelayPolicy::RelayOnly`.
+    fn publish(&self, block: BeaconBlock) -> Result<PublishOutcome, Error> {
+        match (&self.relay, self.relay_policy) {
+            (None, _) => Ok(self.beacon_node.publish_beacon_block(block)?),
+            (Some(relay), RelayPolicy::RelayThenBeaconNode) => {
+                // The Beacon Node is the fallback, so a relay failure is not fatal.
+                if let Err(e) = relay.submit(block.clone()) {
+                    warn!(
+                        self.log, "Block relay failed, publishing to Beacon Node";
+                        "slot" => block.slot, "error" => format!("{:?}", e)
+                    );
+                    if let Some(metrics) = &self.metrics {
+                        metrics.observe_relay_failure();
+                    }
+                }
+                Ok(self.beacon_node.publish_beacon_block(block)?)
+            }
+            (Some(relay), RelayPolicy::RelayOnly) => {
+                relay.submit(block)?;
+                Ok(PublishOutcome::ValidBlock)
+            }
+        }
+    }
+
+    /// Performs the `request`, retrying according to the `retry_policy` whilst it fails with a
+    /// retryable error.
     ///
-    /// Important: this function is presently stubbed-out. It provides ZERO SAFETY.
-    fn safe_to_produce(&self, _block: &BeaconBlock) -> bool {
-        // TODO: ensure the producer doesn't produce slashable blocks.
-        // https://github.com/sigp/lighthouse/issues/160
-        true
+    /// Blocks the thread whilst waiting between attempts.
+    fn with_retries<R, F>(&self, mut request: F) -> Result<R, BeaconNodeError>
+    where
+        F: FnMut() -> Result<R, BeaconNodeError>,
+    {
+        let policy = match self.retry_policy {
+            Some(policy) => policy,
+            None => return request(),
+        };
+
+        let mut attempt = 1;
+        loop {
+            match request() {
+                Err(ref e) if e.is_retryable() && attempt < policy.max_attempts => {
+                    thread::sleep(policy.delay(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
-    /// Record that a block was produced so that slashable votes may not be made in the future.
+    /// Records a block as produced, returning it ready to be signed.
     ///
-    /// !!! UNSAFE !!!
+    /// The block is recorded before it is signed. If the record cannot be stored, an error is
+    /// returned and the block must not be signed.
     ///
-    /// Important: this function is presently stubbed-out. It provides ZERO SAFETY.
-    fn store_produce(&mut self, _block: &BeaconBlock) {
-        // TODO: record this block production to prevent future slashings.
-        // https://github.com/sigp/lighthouse/issues/160
+    /// Important: this function will not check to ensure the block is not slashable. This must be
+    /// done upstream.
+    fn prepare_signing(
+        &mut self,
+        slot: Slot,
+        block: BeaconBlock,
+        is_minimal: bool,
+    ) -> Result<UnsignedBlock, Error> {
+        let root = self.proposal_signing_root(&block);
+        if self.verify_proposal_root && Some(root) != self.expected_proposal_root(slot, &block) {
+            crit!(self.log, "Proposal root mismatch, block not signed"; "slot" => slot);
+            return Err(Error::ProposalRootMismatch(slot));
+        }
+
+        self.store_produce(&block)?;
+
+        Ok(UnsignedBlock {
+            slot,
+            root,
+            block,
+            is_minimal,
+        })
     }
-}
 
-impl From<BeaconNodeError> for Error {
-    fn from(e: BeaconNodeError) -> Error {
-        Error::BeaconNodeError(e)
+    /// Fetches the proposer nonce from the Beacon Node, unless it was already fetched under the
+    /// fork version in effect at `slot`.
+    ///
+    /// Crossing a fork invalidates the cached nonce, so it is always re-fetched for the first
+    /// block produced after a fork.
+    fn refresh_proposer_nonce(&mut self, slot: Slot) -> Result<u64, Error> {
+        let fork_version = self.fork.get_fork_version(slot);
+        match self.nonce_cache {
+            Some((cached_version, nonce)) if cached_version == fork_version => Ok(nonce),
+            _ => {
+                let nonce = self.with_retries(|| self.beacon_node.proposer_nonce(&self.pubkey))?;
+                debug!(
+                    self.log, "Fetched proposer nonce";
+                    "slot" => slot, "fork_version" => fork_version, "nonce" => nonce
+                );
+                self.nonce_cache = Some((fork_version, nonce));
+                Ok(nonce)
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::test_utils::{EpochMap, LocalSigner, SimulatedBeaconNode};
-    use super::*;
-    use slot_clock::TestingSlotClock;
-    use types::{
-        test_utils::{SeedableRng, TestRandom, XorShiftRng},
-        Keypair,
-    };
+    /// Returns the root which the Beacon Node expects to be signed for a `block` requested at
+    /// `slot`.
+    ///
+    /// Derived independently of `proposal_signing_root`: the block root is computed from the
+    /// block as decoded from its SSZ encoding (as the Beacon Node would receive it), rather than
+    /// from the block itself. Returns `None` if the encoded block cannot be decoded.
+    fn expected_proposal_root(&self, slot: Slot, block: &BeaconBlock) -> Option<Hash256> {
+        let (mut received, _) = BeaconBlock::ssz_decode(&ssz_encode(block), 0).ok()?;
+        received.signature = self.spec.empty_signature.clone();
+
+        let proposal = ProposalSignedData {
+            slot,
+            shard: self.spec.beacon_chain_shard_number,
+            block_root: Hash256::from(&received.hash_tree_root()[..]),
+        };
+        let domain = self.fork.get_domain(slot, self.spec.domain_proposal);
+        Some(proposal.signing_root(domain))
+    }
+
+    /// Consumes an `unsigned` block, returning that block with the `signature` from the signer,
+    /// and whether it is a minimal block.
+    ///
+    /// If the signer refused to sign (or the signature fails verification), the `PollOutcome` to
+    /// report is returned instead.
+    fn apply_signature(
+        &mut self,
+        unsigned: UnsignedBlock,
+        signature: Result<Signature, SignerError>,
+    ) -> Result<(BeaconBlock, bool), PollOutcome> {
+        let UnsignedBlock {
+            mut block,
+            root,
+            is_minimal,
+            ..
+        } = unsigned;
+
+        match signature {
+            Err(e) => Err(signer_rejection(&self.log, block.slot, e)),
+            Ok(signature) => {
+                if self.verify_own_signatures
+                    && !self.verify_own_signature(block.slot, root, &signature)
+                {
+                    return Err(signature_mismatch(&self.log, block.slot));
+                }
+                block.signature = signature;
+                Ok((block, is_minimal))
+            }
+        }
+    }
+
+    /// Returns `true` if `signature` is a valid signature of `root` by this validator.
+    ///
+    /// The most recently verified `(root, signature)` pair for each of the last few slots is
+    /// cached, so verifying an identical pair again (e.g., when retrying) is not repeated.
+    pub fn verify_own_signature(
+        &mut self,
+        slot: Slot,
+        root: Hash256,
+        signature: &Signature,
+    ) -> bool {
+        if let Some((cached_root, cached_signature)) = self.verified_signatures.get(slot) {
+            if *cached_root == root && cached_signature == signature {
+                return true;
+            }
+        }
+
+        self.signature_verification_count += 1;
+        if signature.verify(&root[..], &self.pubkey) {
+            self.verified_signatures
+                .insert(slot, (root, signature.clone()));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the number of signature verifications performed, excluding those served from the
+    /// cache.
+    pub fn signature_verification_count(&self) -> u64 {
+        self.signature_verification_count
+    }
+
+    /// Returns `true` if signing a block is safe (non-slashable).
+    fn safe_to_produce(&self, block: &BeaconBlock) -> bool {
+        !self
+            .slashing_protection
+            .is_slashable_block(&self.pubkey, block.slot)
+    }
+
+    /// Record that a block was produced so that slashable votes may not be made in the future.
+    fn store_produce(&mut self, block: &BeaconBlock) -> Result<(), Error> {
+        self.slashing_protection
+            .record_block_proposal(&self.pubkey, block.slot)?;
+        Ok(())
+    }
+}
+
+impl<U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection>
+    BlockProducer<TestingSlotClock, U, V, W, X>
+{
+    /// Advances the slot clock one slot at a time, from the present slot up to `target_slot`
+    /// (inclusive), polling at each slot. Returns each polled slot with its outcome.
+    ///
+    /// Only available with a `TestingSlotClock`, e.g., for simulations. Stops at the first error.
+    pub fn poll_until(&mut self, target_slot: Slot) -> Result<Vec<(Slot, PollOutcome)>, Error> {
+        let mut slot = self.present_slot()?;
+        let mut outcomes = vec![];
+
+        while slot < target_slot {
+            slot += 1;
+            self.slot_clock.set_slot(slot.as_u64());
+            outcomes.push((slot, self.poll()?));
+        }
+
+        Ok(outcomes)
+    }
+}
+
+/// Logs a signature which failed verification against the validator's `pubkey`, returning the
+/// `PollOutcome` reported for `slot`.
+fn signature_mismatch(log: &Logger, slot: Slot) -> PollOutcome {
+    error!(log, "Signer returned a signature by another key"; "slot" => slot);
+    PollOutcome::SignatureMismatch(slot)
+}
+
+/// Logs a `SignerError` and maps it to the `PollOutcome` reported for `slot`.
+fn signer_rejection(log: &Logger, slot: Slot, e: SignerError) -> PollOutcome {
+    error!(log, "Signer rejected block"; "slot" => slot, "reason" => format!("{:?}", e));
+    match e {
+        SignerError::Locked => PollOutcome::SignerLocked(slot),
+        SignerError::UnknownKey => PollOutcome::SignerUnknownKey(slot),
+        SignerError::Backend(reason) => PollOutcome::SignerRejection(slot, reason),
+    }
+}
+
+impl From<BeaconNodeError> for Error {
+    fn from(e: BeaconNodeError) -> Error {
+        Error::BeaconNodeError(e)
+    }
+}
+
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection> Drop
+    for BlockProducer<T, U, V, W, X>
+{
+    /// Releases the `ProcessLock` (if any).
+    fn drop(&mut self) {
+        if let Some(process_lock) = &self.process_lock {
+            let _ = process_lock.release(&self.pubkey);
+        }
+    }
+}
+
+impl From<ProcessLockError> for Error {
+    fn from(e: ProcessLockError) -> Error {
+        Error::ProcessLockError(e)
+    }
+}
+
+impl From<BlockRelayError> for Error {
+    fn from(e: BlockRelayError) -> Error {
+        Error::BlockRelayError(e)
+    }
+}
+
+impl From<SlashingProtectionError> for Error {
+    fn from(e: SlashingProtectionError) -> Error {
+        Error::SlashingProtectionError(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{
+        null_logger, BlockProducerMocks, BlockProducerTester, EpochMap, LocalSigner,
+        SimulatedBeaconNode, SimulatedBlockRelay,
+    };
+    use super::*;
+    use slot_clock::TestingSlotClock;
+    use std::fmt;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use types::{
+        test_utils::{SeedableRng, TestRandom, XorShiftRng},
+        Keypair,
+    };
 
     // TODO: implement more thorough testing.
     // https://github.com/sigp/lighthouse/issues/160
@@ -228,33 +1322,14 @@ mod tests {
 
     #[test]
     pub fn polling() {
-        let mut rng = XorShiftRng::from_seed([42; 16]);
-
-        let spec = Arc::new(ChainSpec::foundation());
-        let slot_clock = Arc::new(TestingSlotClock::new(0));
-        let beacon_node = Arc::new(SimulatedBeaconNode::default());
-        let signer = Arc::new(LocalSigner::new(Keypair::random()));
-
-        let mut epoch_map = EpochMap::new(spec.epoch_length);
-        let produce_slot = Slot::new(100);
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let BlockProducerMocks {
+            spec,
+            slot_clock,
+            produce_slot,
+            ..
+        } = mocks;
         let produce_epoch = produce_slot.epoch(spec.epoch_length);
-        epoch_map.map.insert(produce_epoch, produce_slot);
-        let epoch_map = Arc::new(epoch_map);
-        let keypair = Keypair::random();
-
-        let mut block_producer = BlockProducer::new(
-            spec.clone(),
-            keypair.pk.clone(),
-            epoch_map.clone(),
-            slot_clock.clone(),
-            beacon_node.clone(),
-            signer.clone(),
-        );
-
-        // Configure responses from the BeaconNode.
-        beacon_node.set_next_produce_result(Ok(Some(BeaconBlock::random_for_test(&mut rng))));
-        beacon_node.set_next_publish_result(Ok(PublishOutcome::ValidBlock));
-        beacon_node.set_next_nonce_result(Ok(0));
 
         // One slot before production slot...
         slot_clock.set_slot(produce_slot.as_u64() - 1);
@@ -267,7 +1342,10 @@ mod tests {
         slot_clock.set_slot(produce_slot.as_u64());
         assert_eq!(
             block_producer.poll(),
-            Ok(PollOutcome::BlockProduced(produce_slot.into()))
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot.into(),
+                PublishOutcome::ValidBlock
+            ))
         );
 
         // Trying the same produce slot again...
@@ -292,4 +1370,1850 @@ mod tests {
             Ok(PollOutcome::ProducerDutiesUnknown(Slot::new(slot)))
         );
     }
+
+    #[test]
+    pub fn minimal_block_on_timeout() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_beacon_node_failure(BeaconNodeError::Timeout)
+            .build();
+        block_producer.prefer_minimal_on_timeout = true;
+
+        // Configure the BeaconNode to time out on a full block, but succeed on a minimal one.
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = mocks.produce_slot;
+        mocks
+            .beacon_node
+            .set_next_produce_minimal_result(Ok(ProduceOutcome::Block(block)));
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProducedMinimal(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(
+            *mocks.beacon_node.produce_minimal_input.read().unwrap(),
+            mocks.beacon_node.produce_input.read().unwrap().clone()
+        );
+    }
+
+    #[test]
+    pub fn crossing_a_fork() {
+        let mut spec = ChainSpec::foundation();
+        let first_slot = Slot::new(10);
+        let pre_fork_slot = Slot::new(100);
+        let post_fork_slot = pre_fork_slot + spec.epoch_length;
+        spec.genesis_fork_version = 1;
+        spec.fork_version = 2;
+        spec.fork_epoch = post_fork_slot.epoch(spec.epoch_length);
+        let fork = spec.fork();
+        let domain_randao = spec.domain_randao;
+
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_spec(spec)
+            .with_keypair(Keypair::random())
+            .with_duties(vec![first_slot, pre_fork_slot, post_fork_slot])
+            .build();
+        block_producer.track_proposer_nonce = true;
+
+        // Before the fork, the nonce is fetched once...
+        mocks.beacon_node.set_next_nonce_result(Ok(7));
+        mocks.slot_clock.set_slot(first_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                first_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(
+            *mocks.beacon_node.nonce_input.read().unwrap(),
+            Some(mocks.keypair.pk.clone())
+        );
+        assert_eq!(block_producer.proposer_nonce(), Some(7));
+
+        // ... and then cached.
+        *mocks.beacon_node.nonce_input.write().unwrap() = None;
+        mocks.slot_clock.set_slot(pre_fork_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProduced(
+                pre_fork_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert!(mocks.beacon_node.nonce_input.read().unwrap().is_none());
+        assert_eq!(block_producer.proposer_nonce(), Some(7));
+        assert_eq!(
+            block_producer.domain(pre_fork_slot, domain_randao),
+            fork.get_domain(pre_fork_slot, domain_randao)
+        );
+
+        // After the fork, the post-fork domain must be used and the nonce must be re-fetched.
+        *mocks.beacon_node.nonce_input.write().unwrap() = None;
+        mocks.beacon_node.set_next_nonce_result(Ok(0));
+        mocks.slot_clock.set_slot(post_fork_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProduced(
+                post_fork_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(
+            *mocks.beacon_node.nonce_input.read().unwrap(),
+            Some(mocks.keypair.pk.clone())
+        );
+        assert_eq!(block_producer.proposer_nonce(), Some(0));
+        let (_, randao_reveal, _) = mocks
+            .beacon_node
+            .produce_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        let post_fork_root = post_fork_slot
+            .epoch(mocks.spec.epoch_length)
+            .randao_signing_root(fork.get_domain(post_fork_slot, domain_randao));
+        assert!(randao_reveal.verify(&post_fork_root[..], &mocks.keypair.pk));
+        assert_eq!(
+            block_producer.domain(post_fork_slot, domain_randao),
+            (fork.post_fork_version << 32) + domain_randao
+        );
+        assert_ne!(
+            block_producer.domain(pre_fork_slot, domain_randao),
+            block_producer.domain(post_fork_slot, domain_randao)
+        );
+    }
+
+    #[test]
+    pub fn validator_pending_activation() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.check_validator_status = true;
+
+        mocks
+            .beacon_node
+            .set_next_validator_status_result(Ok(ValidatorStatus::Pending));
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::ValidatorPendingActivation(mocks.produce_slot))
+        );
+        // No block should have been requested from the node.
+        assert_eq!(*mocks.beacon_node.produce_input.read().unwrap(), None);
+    }
+
+    #[test]
+    pub fn proposal_signing_root() {
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_keypair(Keypair::random())
+            .build();
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        let block = mocks
+            .beacon_node
+            .publish_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        let signing_root = block_producer.proposal_signing_root(&block);
+        assert!(block.signature.verify(&signing_root[..], &mocks.keypair.pk));
+    }
+
+    #[test]
+    pub fn slashable_block_not_produced() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let produce_slot = mocks.produce_slot;
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        // Forget that the slot was processed and attempt to produce at the same slot again.
+        block_producer.last_processed_slot = None;
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlashableBlockNotProduced(produce_slot))
+        );
+
+        // A new producer sharing the same records must also refuse to produce.
+        let mut block_producer = BlockProducer::new(
+            mocks.spec.clone(),
+            mocks.keypair.pk.clone(),
+            mocks.epoch_map.clone(),
+            mocks.slot_clock.clone(),
+            mocks.beacon_node.clone(),
+            mocks.signer.clone(),
+            mocks.slashing_protection.clone(),
+            null_logger(),
+        );
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlashableBlockNotProduced(produce_slot))
+        );
+    }
+
+    #[test]
+    pub fn insufficient_peers() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.min_peers = Some(8);
+
+        mocks.beacon_node.set_next_peer_count_result(Ok(7));
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::InsufficientPeers(mocks.produce_slot))
+        );
+        // No block should have been requested from the node.
+        assert_eq!(*mocks.beacon_node.produce_input.read().unwrap(), None);
+    }
+
+    #[test]
+    pub fn epoch_length_mismatch() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let BlockProducerMocks {
+            spec,
+            slot_clock,
+            beacon_node,
+            produce_slot,
+            ..
+        } = mocks;
+
+        // The clock and node agree with the spec...
+        slot_clock.set_epoch_length(spec.epoch_length);
+        beacon_node.set_next_epoch_length_result(Ok(Some(spec.epoch_length)));
+        assert_eq!(block_producer.check_epoch_length(), Ok(()));
+
+        // The clock disagrees with the spec...
+        slot_clock.set_epoch_length(spec.epoch_length * 2);
+        slot_clock.set_slot(produce_slot.as_u64() - 1);
+        assert_eq!(block_producer.poll(), Err(Error::EpochLengthMismatch));
+
+        // The node disagrees with the spec...
+        slot_clock.set_epoch_length(spec.epoch_length);
+        beacon_node.set_next_epoch_length_result(Ok(Some(spec.epoch_length + 1)));
+        assert_eq!(block_producer.poll(), Err(Error::EpochLengthMismatch));
+
+        // The node does not report an epoch length...
+        beacon_node.set_next_epoch_length_result(Ok(None));
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProductionNotRequired(produce_slot - 1))
+        );
+    }
+
+    #[test]
+    pub fn randao_reveal_domain() {
+        let keypair = Keypair::random();
+
+        // Returns the RANDAO reveal produced with the given `domain_randao`.
+        let randao_reveal = |domain_randao: u64| {
+            let mut spec = ChainSpec::foundation();
+            spec.domain_randao = domain_randao;
+            let (mut block_producer, mocks) = BlockProducerTester::new()
+                .with_spec(spec)
+                .with_keypair(keypair.clone())
+                .build();
+
+            mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+            assert_eq!(
+                block_producer.poll(),
+                Ok(PollOutcome::FirstBlockProduced(
+                    mocks.produce_slot,
+                    PublishOutcome::ValidBlock
+                ))
+            );
+
+            let (_, randao_reveal, _) = mocks
+                .beacon_node
+                .produce_input
+                .read()
+                .unwrap()
+                .clone()
+                .unwrap();
+            assert!(randao_reveal.verify(
+                &block_producer.randao_signing_root(mocks.produce_slot)[..],
+                &keypair.pk
+            ));
+            randao_reveal
+        };
+
+        assert_ne!(randao_reveal(4), randao_reveal(5));
+    }
+
+    #[test]
+    pub fn proposal_domain() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let produce_slot = Slot::new(100);
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        let keypair = Keypair::random();
+
+        // Returns the signature of the same block, proposed with the given `domain_proposal`,
+        // checking the signed message was the signing root for that domain.
+        let proposal_signature = |domain_proposal: u64| {
+            let mut spec = ChainSpec::foundation();
+            spec.domain_proposal = domain_proposal;
+            let (mut block_producer, mocks) = BlockProducerTester::new()
+                .with_spec(spec)
+                .with_produce_slot(produce_slot)
+                .build();
+            block_producer.signer = Arc::new(LocalSigner::new(keypair.clone()));
+            mocks
+                .beacon_node
+                .set_next_produce_result(Ok(ProduceOutcome::Block(block.clone())));
+
+            mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+            assert_eq!(
+                block_producer.poll(),
+                Ok(PollOutcome::FirstBlockProduced(
+                    mocks.produce_slot,
+                    PublishOutcome::ValidBlock
+                ))
+            );
+
+            let published = mocks
+                .beacon_node
+                .publish_input
+                .read()
+                .unwrap()
+                .clone()
+                .unwrap();
+            let domain = block_producer
+                .fork
+                .get_domain(published.slot, domain_proposal);
+            assert!(published.signature.verify(
+                &published.proposal_signing_root(&mocks.spec, domain)[..],
+                &keypair.pk
+            ));
+            assert_eq!(
+                block_producer.proposal_signing_root(&published),
+                published.proposal_signing_root(&mocks.spec, domain)
+            );
+            published.signature
+        };
+
+        assert_ne!(proposal_signature(2), proposal_signature(3));
+    }
+
+    #[test]
+    pub fn audit_interchange() {
+        let (block_producer, mocks) = BlockProducerTester::new().build();
+        let BlockProducerMocks {
+            keypair,
+            slashing_protection,
+            ..
+        } = mocks;
+        let other_keypair = Keypair::random();
+        let hex = |pubkey: &PublicKey| -> String {
+            pubkey
+                .as_raw()
+                .as_bytes()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect()
+        };
+
+        // Slot 10 is already protected against, so only slots 20 and 30 should be reported.
+        slashing_protection
+            .record_block_proposal(&keypair.pk, Slot::new(10))
+            .unwrap();
+
+        let root = "42".repeat(32);
+        let interchange = format!(
+            r#"{{
+                "metadata": {{
+                    "interchange_format_version": "5",
+                    "genesis_validators_root": "0x{root}"
+                }},
+                "data": [
+                    {{
+                        "pubkey": "0x{key}",
+                        "signed_blocks": [
+                            {{ "slot": "10", "signing_root": "0x{root}" }},
+                            {{ "slot": "30" }},
+                            {{ "slot": "20", "signing_root": "0x{root}" }}
+                        ],
+                        "signed_attestations": []
+                    }},
+                    {{
+                        "pubkey": "0x{other_key}",
+                        "signed_blocks": [{{ "slot": "40" }}],
+                        "signed_attestations": []
+                    }}
+                ]
+            }}"#,
+            key = hex(&keypair.pk),
+            other_key = hex(&other_keypair.pk),
+            root = root
+        );
+        let path = std::env::temp_dir().join(format!(
+            "block_producer_audit_interchange_test_{}",
+            std::process::id()
+        ));
+        std::fs::write(&path, interchange).unwrap();
+
+        let audit = block_producer.audit_interchange(&path).unwrap();
+        assert_eq!(
+            audit.conflicts,
+            vec![
+                SlotConflict {
+                    slot: Slot::new(30),
+                    signing_root: None,
+                },
+                SlotConflict {
+                    slot: Slot::new(20),
+                    signing_root: Some(Hash256::from(&[0x42; 32][..])),
+                },
+            ]
+        );
+        // Importing would make every slot up to 30 slashable, including the unsigned slot 25.
+        assert_eq!(audit.watermark, Some(Slot::new(30)));
+        slashing_protection
+            .record_block_proposal(&keypair.pk, audit.watermark.unwrap())
+            .unwrap();
+        assert!(slashing_protection.is_slashable_block(&keypair.pk, Slot::new(25)));
+
+        // Once imported, there is nothing to report.
+        assert_eq!(
+            block_producer.audit_interchange(&path),
+            Ok(InterchangeAudit {
+                conflicts: vec![],
+                watermark: None,
+            })
+        );
+
+        // Other interchange formats are rejected.
+        std::fs::write(&path, "{ \"metadata\": {}, \"data\": [] }").unwrap();
+        assert!(block_producer.audit_interchange(&path).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    pub fn graffiti() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+
+        // Short graffiti is zero-padded...
+        block_producer.set_graffiti("lighthouse");
+        let mut expected = [0; 32];
+        expected[..10].copy_from_slice(b"lighthouse");
+        assert_eq!(*block_producer.graffiti(), expected);
+
+        // Long graffiti is truncated on a character boundary...
+        block_producer.set_graffiti(&format!("{}é", "a".repeat(31)));
+        let mut expected = [0; 32];
+        expected[..31].copy_from_slice("a".repeat(31).as_bytes());
+        assert_eq!(*block_producer.graffiti(), expected);
+
+        block_producer.set_graffiti("lighthouse");
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        let block = mocks
+            .beacon_node
+            .publish_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        assert_eq!(
+            block.body.graffiti,
+            Hash256::from(&block_producer.graffiti()[..])
+        );
+        assert_eq!(&block.body.graffiti[..10], b"lighthouse");
+    }
+
+    #[test]
+    pub fn signer_errors() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        // Produce at the first slot of four consecutive epochs.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let produce_slots: Vec<Slot> = (1..5).map(|i| Slot::new(i * epoch_length)).collect();
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(produce_slots.clone())
+            .build();
+
+        let expected = vec![
+            (
+                Some(SignerError::Locked),
+                PollOutcome::SignerLocked(produce_slots[0]),
+            ),
+            (
+                Some(SignerError::UnknownKey),
+                PollOutcome::SignerUnknownKey(produce_slots[1]),
+            ),
+            (
+                Some(SignerError::Backend("offline".to_string())),
+                PollOutcome::SignerRejection(produce_slots[2], "offline".to_string()),
+            ),
+            // Once the signer recovers, blocks are produced again.
+            (
+                None,
+                PollOutcome::FirstBlockProduced(produce_slots[3], PublishOutcome::ValidBlock),
+            ),
+        ];
+
+        // The RANDAO reveal is signed before the block is requested, so the node is only asked
+        // for a block once the signer has recovered.
+        for (slot, (error, outcome)) in produce_slots.iter().zip(expected) {
+            let mut block = BeaconBlock::random_for_test(&mut rng);
+            block.slot = *slot;
+            mocks
+                .beacon_node
+                .set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+            mocks.signer.set_next_error(error);
+
+            mocks.slot_clock.set_slot(slot.as_u64());
+            assert_eq!(block_producer.poll(), Ok(outcome));
+        }
+    }
+
+    #[test]
+    pub fn slots_since_duties_updated() {
+        // Duties are only known for epoch 1.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let duties_start_slot = Epoch::new(1).start_slot(epoch_length);
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_produce_slot(duties_start_slot)
+            .build();
+
+        assert_eq!(block_producer.slots_since_duties_updated(), None);
+
+        // Within the duties epoch...
+        mocks.slot_clock.set_slot(duties_start_slot.as_u64() + 1);
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProductionNotRequired(
+                duties_start_slot + 1
+            ))
+        );
+        assert_eq!(block_producer.slots_since_duties_updated(), Some(1));
+
+        // The clock moves beyond the duties epoch, but the duties are not updated...
+        for i in 1..4 {
+            let slot = duties_start_slot + epoch_length * i;
+            mocks.slot_clock.set_slot(slot.as_u64());
+            assert_eq!(
+                block_producer.poll(),
+                Ok(PollOutcome::ProducerDutiesUnknown(slot))
+            );
+            assert_eq!(
+                block_producer.slots_since_duties_updated(),
+                Some(epoch_length * i)
+            );
+        }
+    }
+
+    #[test]
+    pub fn retries_unavailable_beacon_node() {
+        // Produce at the first slot of two consecutive epochs.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let produce_slots: Vec<Slot> = (1..3).map(|i| Slot::new(i * epoch_length)).collect();
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(produce_slots.clone())
+            .build();
+        block_producer.retry_policy = Some(RetryPolicy::new(3, Duration::from_millis(1)));
+        let BlockProducerMocks {
+            slot_clock,
+            beacon_node,
+            ..
+        } = mocks;
+        let unavailable = BeaconNodeError::Unavailable("connection refused".to_string());
+
+        // The Beacon Node fails twice, then succeeds on the third attempt...
+        beacon_node.push_produce_failure(unavailable.clone());
+        beacon_node.push_produce_failure(unavailable.clone());
+
+        slot_clock.set_slot(produce_slots[0].as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slots[0],
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert!(beacon_node.produce_failures.read().unwrap().is_empty());
+
+        // The Beacon Node fails more times than there are attempts...
+        for _ in 0..3 {
+            beacon_node.push_produce_failure(unavailable.clone());
+        }
+
+        slot_clock.set_slot(produce_slots[1].as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Err(Error::BeaconNodeError(unavailable.clone()))
+        );
+        assert!(beacon_node.produce_failures.read().unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn does_not_retry_permanent_failure() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.retry_policy = Some(RetryPolicy::new(3, Duration::from_millis(1)));
+
+        mocks
+            .beacon_node
+            .set_next_produce_result(Ok(ProduceOutcome::NoBlockAvailable(
+                NoBlockReason::Unspecified,
+            )));
+        mocks
+            .beacon_node
+            .push_produce_failure(BeaconNodeError::DecodeFailure);
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Err(Error::BeaconNodeError(BeaconNodeError::DecodeFailure))
+        );
+    }
+
+    #[test]
+    pub fn quiet_window() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        // Produce at the first slot of two consecutive epochs.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let inside_slot = Slot::new(epoch_length);
+        let outside_slot = Slot::new(2 * epoch_length);
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(vec![inside_slot, outside_slot])
+            .build();
+        block_producer.set_quiet_range(inside_slot - 1, outside_slot - 1);
+
+        // No block is requested inside the quiet window, so only the block outside it is queued.
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = outside_slot;
+        mocks
+            .beacon_node
+            .set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+
+        // A scheduled proposal inside the quiet window is skipped...
+        mocks.slot_clock.set_slot(inside_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::QuietWindow(inside_slot))
+        );
+        assert_eq!(*mocks.beacon_node.produce_input.read().unwrap(), None);
+
+        // The last slot of the quiet window is also skipped...
+        mocks.slot_clock.set_slot(outside_slot.as_u64() - 1);
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::QuietWindow(outside_slot - 1))
+        );
+
+        // A scheduled proposal just outside the quiet window proceeds...
+        mocks.slot_clock.set_slot(outside_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                outside_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+    }
+
+    #[test]
+    pub fn verify_own_signatures() {
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_keypair(Keypair::random())
+            .build();
+        block_producer.verify_own_signatures = true;
+        let produce_slot = mocks.produce_slot;
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        // Both the RANDAO reveal and the block signature were verified.
+        assert_eq!(block_producer.signature_verification_count(), 2);
+
+        // Verifying the same block signature again is served from the cache.
+        let block = mocks
+            .beacon_node
+            .publish_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        let root = block_producer.proposal_signing_root(&block);
+        assert!(block_producer.verify_own_signature(produce_slot, root, &block.signature));
+        assert_eq!(block_producer.signature_verification_count(), 2);
+
+        // A signature by some other key is verified (and rejected).
+        let other_signature = Signature::new(&root[..], &Keypair::random().sk);
+        assert!(!block_producer.verify_own_signature(produce_slot, root, &other_signature));
+        assert_eq!(block_producer.signature_verification_count(), 3);
+    }
+
+    #[test]
+    pub fn first_block_produced() {
+        // Produce at the first slot of two consecutive epochs.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let produce_slots: Vec<Slot> = (1..3).map(|i| Slot::new(i * epoch_length)).collect();
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(produce_slots.clone())
+            .build();
+
+        let expected = vec![
+            PollOutcome::FirstBlockProduced(produce_slots[0], PublishOutcome::ValidBlock),
+            PollOutcome::BlockProduced(produce_slots[1], PublishOutcome::ValidBlock),
+        ];
+
+        for (slot, outcome) in produce_slots.iter().zip(expected) {
+            mocks.slot_clock.set_slot(slot.as_u64());
+            assert_eq!(block_producer.poll(), Ok(outcome));
+        }
+    }
+
+    #[test]
+    pub fn malformed_block_not_signed() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let produce_slot = mocks.produce_slot;
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        block.state_root = Hash256::zero();
+        mocks
+            .beacon_node
+            .set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BeaconNodeReturnedMalformedBlock(produce_slot))
+        );
+
+        // The block was neither recorded as signed nor published.
+        assert!(!mocks
+            .slashing_protection
+            .is_slashable_block(&mocks.keypair.pk, produce_slot));
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+    }
+
+    #[test]
+    pub fn block_for_another_slot_not_signed() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let produce_slot = mocks.produce_slot;
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot + 1;
+        mocks
+            .beacon_node
+            .set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BeaconNodeReturnedMalformedBlock(produce_slot))
+        );
+
+        // The block was not signed, nor recorded at either slot.
+        assert_eq!(mocks.signer.signed_for().len(), 1);
+        for slot in &[produce_slot, produce_slot + 1] {
+            assert!(!mocks
+                .slashing_protection
+                .is_slashable_block(&mocks.keypair.pk, *slot));
+        }
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+    }
+
+    #[test]
+    pub fn rebroadcast_last() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let BlockProducerMocks {
+            slot_clock,
+            beacon_node,
+            signer,
+            produce_slot,
+            ..
+        } = mocks;
+
+        // Nothing has been signed yet.
+        assert_eq!(
+            block_producer.rebroadcast_last(),
+            Err(Error::NoBlockToRebroadcast)
+        );
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        let published = beacon_node.publish_input.read().unwrap().clone().unwrap();
+
+        // Any attempt to sign would now fail.
+        signer.set_next_error(Some(SignerError::Locked));
+        *beacon_node.publish_input.write().unwrap() = None;
+
+        assert_eq!(
+            block_producer.rebroadcast_last(),
+            Ok(PollOutcome::Rebroadcast(produce_slot))
+        );
+        let rebroadcast = beacon_node.publish_input.read().unwrap().clone().unwrap();
+        assert_eq!(rebroadcast, published);
+        assert_eq!(rebroadcast.hash_tree_root(), published.hash_tree_root());
+    }
+
+    #[test]
+    pub fn block_relay() {
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let produce_slots: Vec<Slot> = (1..4).map(|i| Slot::new(i * epoch_length)).collect();
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_keypair(Keypair::random())
+            .with_duties(produce_slots.clone())
+            .build();
+        let BlockProducerMocks {
+            keypair,
+            slot_clock,
+            beacon_node,
+            ..
+        } = mocks;
+        let relay = Arc::new(SimulatedBlockRelay::default());
+        block_producer.relay = Some(relay.clone());
+        let metrics = Arc::new(BlockProducerMetrics::new().unwrap());
+        block_producer.metrics = Some(metrics.clone());
+
+        // The relay receives the signed block, then it is published to the Beacon Node.
+        slot_clock.set_slot(produce_slots[0].as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slots[0],
+                PublishOutcome::ValidBlock
+            ))
+        );
+        let relayed = relay.submitted.read().unwrap()[0].clone();
+        let signing_root = block_producer.proposal_signing_root(&relayed);
+        assert!(relayed.signature.verify(&signing_root[..], &keypair.pk));
+        assert_eq!(
+            beacon_node.publish_input.read().unwrap().clone(),
+            Some(relayed)
+        );
+
+        // A relay failure does not prevent publishing to the Beacon Node.
+        relay.set_submit_error(Some(BlockRelayError::RemoteFailure("down".to_string())));
+        *beacon_node.publish_input.write().unwrap() = None;
+        slot_clock.set_slot(produce_slots[1].as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProduced(
+                produce_slots[1],
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(relay.submitted.read().unwrap().len(), 2);
+        assert!(beacon_node.publish_input.read().unwrap().is_some());
+        assert_eq!(metrics.relay_failure_count(), 1);
+
+        // With `RelayOnly`, the Beacon Node does not receive the block and relay failures are
+        // errors.
+        block_producer.relay_policy = RelayPolicy::RelayOnly;
+        *beacon_node.publish_input.write().unwrap() = None;
+        assert_eq!(
+            block_producer.rebroadcast_last(),
+            Err(Error::BlockRelayError(BlockRelayError::RemoteFailure(
+                "down".to_string()
+            )))
+        );
+
+        relay.set_submit_error(None);
+        slot_clock.set_slot(produce_slots[2].as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProduced(
+                produce_slots[2],
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(relay.submitted.read().unwrap().len(), 4);
+        assert_eq!(relay.submitted.read().unwrap()[3].slot, produce_slots[2]);
+        assert!(beacon_node.publish_input.read().unwrap().is_none());
+    }
+
+    #[test]
+    pub fn process_lock() {
+        let (_, mocks) = BlockProducerTester::new().build();
+        let process_lock = Arc::new(InMemoryProcessLock::default());
+
+        let new_block_producer = || {
+            BlockProducer::new(
+                mocks.spec.clone(),
+                mocks.keypair.pk.clone(),
+                mocks.epoch_map.clone(),
+                mocks.slot_clock.clone(),
+                mocks.beacon_node.clone(),
+                mocks.signer.clone(),
+                mocks.slashing_protection.clone(),
+                null_logger(),
+            )
+            .with_process_lock(process_lock.clone())
+        };
+
+        // Another producer already holds the lock.
+        process_lock.acquire(&mocks.keypair.pk).unwrap();
+        assert_eq!(
+            new_block_producer().err(),
+            Some(Error::ProcessLockError(ProcessLockError::AlreadyHeld))
+        );
+
+        process_lock.release(&mocks.keypair.pk).unwrap();
+        let block_producer = new_block_producer().unwrap();
+        assert!(new_block_producer().is_err());
+
+        // The lock is released when the producer is dropped.
+        drop(block_producer);
+        assert!(new_block_producer().is_ok());
+    }
+
+    /// A logged record: its message and key/value pairs.
+    type LoggedRecord = (String, Vec<(String, String)>);
+
+    /// A drain which records each record logged to it.
+    struct RecordingDrain(Arc<Mutex<Vec<LoggedRecord>>>);
+
+    /// Collects the key/value pairs of a single record.
+    struct KVCollector(Vec<(String, String)>);
+
+    impl slog::Serializer for KVCollector {
+        fn emit_arguments(&mut self, key: slog::Key, val: &fmt::Arguments) -> slog::Result {
+            self.0.push((key.to_string(), format!("{}", val)));
+            Ok(())
+        }
+    }
+
+    impl slog::Drain for RecordingDrain {
+        type Ok = ();
+        type Err = slog::Never;
+
+        fn log(
+            &self,
+            record: &slog::Record,
+            _values: &slog::OwnedKVList,
+        ) -> Result<(), slog::Never> {
+            let mut collector = KVCollector(vec![]);
+            record.kv().serialize(record, &mut collector).unwrap();
+            self.0
+                .lock()
+                .unwrap()
+                .push((format!("{}", record.msg()), collector.0));
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn logging() {
+        let records = Arc::new(Mutex::new(vec![]));
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_logger(Logger::root(RecordingDrain(records.clone()), slog::o!()))
+            .build();
+        let produce_slot = mocks.produce_slot;
+
+        // One slot prior to the production slot, no block is required.
+        mocks.slot_clock.set_slot(produce_slot.as_u64() - 1);
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProductionNotRequired(produce_slot - 1))
+        );
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        let records = records.lock().unwrap().clone();
+        assert_eq!(records.len(), 2);
+
+        let (msg, pairs) = &records[0];
+        assert_eq!(msg, "Block production not required");
+        assert_eq!(
+            pairs,
+            &vec![("slot".to_string(), (produce_slot - 1).to_string())]
+        );
+
+        let (msg, pairs) = &records[1];
+        assert_eq!(msg, "Block produced");
+        assert!(pairs.contains(&("slot".to_string(), produce_slot.to_string())));
+        assert!(pairs.contains(&("minimal".to_string(), "false".to_string())));
+        assert!(pairs.contains(&("first".to_string(), "true".to_string())));
+    }
+
+    #[test]
+    pub fn metrics() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let metrics = Arc::new(BlockProducerMetrics::new().unwrap());
+        block_producer.metrics = Some(metrics.clone());
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(metrics.outcome_count("first_block_produced"), 0);
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(metrics.outcome_count("first_block_produced"), 1);
+
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlotAlreadyProcessed(mocks.produce_slot))
+        );
+        assert_eq!(metrics.outcome_count("slot_already_processed"), 1);
+        assert_eq!(metrics.outcome_count("first_block_produced"), 1);
+    }
+
+    #[test]
+    pub fn produce_once() {
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_keypair(Keypair::random())
+            .build();
+        let produce_slot = mocks.produce_slot;
+
+        let block = block_producer.produce_once(produce_slot).unwrap().unwrap();
+
+        let signing_root = block_producer.proposal_signing_root(&block);
+        assert!(block.signature.verify(&signing_root[..], &mocks.keypair.pk));
+        assert!(mocks
+            .slashing_protection
+            .is_slashable_block(&mocks.keypair.pk, produce_slot));
+        // The block was not published.
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+
+        // A second block at the same slot would be slashable.
+        assert_eq!(block_producer.produce_once(produce_slot), Ok(None));
+    }
+
+    #[test]
+    pub fn randao_reveal_mismatch() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let produce_slot = mocks.produce_slot;
+
+        // The block carries the RANDAO reveal of another validator.
+        let other_proposer = Keypair::random();
+        mocks
+            .beacon_node
+            .set_randao_reveal_override(Some(Signature::new(&[42], &other_proposer.sk)));
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::RandaoRevealMismatch(produce_slot))
+        );
+
+        // The block was neither recorded as signed nor published.
+        assert!(!mocks
+            .slashing_protection
+            .is_slashable_block(&mocks.keypair.pk, produce_slot));
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+    }
+
+    #[test]
+    pub fn poll_catch_up() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let produce_slot = mocks.produce_slot;
+
+        // The first poll has no missed slots.
+        mocks.slot_clock.set_slot(produce_slot.as_u64() - 2);
+        assert_eq!(
+            block_producer.poll_catch_up(),
+            Ok(vec![PollOutcome::BlockProductionNotRequired(
+                produce_slot - 2
+            )])
+        );
+
+        // Jump forward by 5 slots, past the produce slot.
+        mocks.slot_clock.set_slot(produce_slot.as_u64() + 3);
+        assert_eq!(
+            block_producer.poll_catch_up(),
+            Ok(vec![
+                PollOutcome::SlotMissed(produce_slot - 1),
+                PollOutcome::ProposalMissed(produce_slot),
+                PollOutcome::SlotMissed(produce_slot + 1),
+                PollOutcome::SlotMissed(produce_slot + 2),
+                PollOutcome::BlockProductionNotRequired(produce_slot + 3),
+            ])
+        );
+
+        // No block was produced for the missed produce slot.
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+
+        // Polling the same slot again has no missed slots.
+        assert_eq!(
+            block_producer.poll_catch_up(),
+            Ok(vec![PollOutcome::BlockProductionNotRequired(
+                produce_slot + 3
+            )])
+        );
+    }
+
+    #[test]
+    pub fn poll_catch_up_is_capped() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let start_slot = mocks.produce_slot - 2;
+
+        mocks.slot_clock.set_slot(start_slot.as_u64());
+        block_producer.poll_catch_up().unwrap();
+
+        // Only the most recent missed slots are reported.
+        let present_slot = start_slot + MAX_MISSED_SLOTS_REPORTED * 3;
+        mocks.slot_clock.set_slot(present_slot.as_u64());
+        let outcomes = block_producer.poll_catch_up().unwrap();
+        assert_eq!(outcomes.len() as u64, MAX_MISSED_SLOTS_REPORTED + 1);
+        assert_eq!(
+            outcomes[0],
+            PollOutcome::SlotMissed(present_slot - MAX_MISSED_SLOTS_REPORTED)
+        );
+        assert_eq!(
+            outcomes[outcomes.len() - 2],
+            PollOutcome::SlotMissed(present_slot - 1)
+        );
+    }
+
+    #[test]
+    pub fn poll_with_lead() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.production_lead = Duration::from_millis(500);
+        let BlockProducerMocks {
+            spec,
+            slot_clock,
+            produce_slot,
+            ..
+        } = mocks;
+
+        let slot_duration = Duration::from_secs(spec.slot_duration);
+        slot_clock.set_slot(produce_slot.as_u64() - 1);
+
+        // More than `production_lead` before the produce slot...
+        slot_clock.set_duration_into_slot(slot_duration - Duration::from_millis(501));
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::BlockProductionNotRequired(produce_slot - 1))
+        );
+
+        // Exactly `production_lead` before the produce slot...
+        slot_clock.set_duration_into_slot(slot_duration - Duration::from_millis(500));
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        // Once the produce slot has arrived...
+        slot_clock.set_slot(produce_slot.as_u64());
+        slot_clock.set_duration_into_slot(Duration::from_millis(100));
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::SlotAlreadyProcessed(produce_slot))
+        );
+    }
+
+    #[test]
+    pub fn poll_with_lead_present_slot_first() {
+        // Produce at the last slot of an epoch and the first slot of the next.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let produce_slots = vec![Slot::new(2 * epoch_length - 1), Slot::new(2 * epoch_length)];
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(produce_slots.clone())
+            .build();
+        block_producer.production_lead = Duration::from_millis(500);
+
+        // The first poll is already within the lead of the next slot...
+        let slot_duration = Duration::from_secs(mocks.spec.slot_duration);
+        mocks.slot_clock.set_slot(produce_slots[0].as_u64());
+        mocks
+            .slot_clock
+            .set_duration_into_slot(slot_duration - Duration::from_millis(100));
+
+        // ...but the present slot has not been processed, so it is produced first.
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slots[0],
+                PublishOutcome::ValidBlock
+            ))
+        );
+        // Then the next slot.
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::BlockProduced(
+                produce_slots[1],
+                PublishOutcome::ValidBlock
+            ))
+        );
+    }
+
+    #[test]
+    pub fn poll_with_lead_present_slot_duties_unknown() {
+        // Only the duties of the next epoch are known.
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let present_slot = Slot::new(2 * epoch_length - 1);
+        let next_slot = present_slot + 1;
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(vec![next_slot])
+            .build();
+        block_producer.production_lead = Duration::from_millis(500);
+
+        let slot_duration = Duration::from_secs(mocks.spec.slot_duration);
+        mocks.slot_clock.set_slot(present_slot.as_u64());
+        mocks
+            .slot_clock
+            .set_duration_into_slot(slot_duration - Duration::from_millis(100));
+
+        // The unknown duties of the present slot do not prevent the next slot being produced.
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::FirstBlockProduced(
+                next_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert_eq!(block_producer.last_polled_slot, Some(next_slot));
+    }
+
+    #[test]
+    pub fn poll_with_lead_present_slot_error() {
+        let epoch_length = ChainSpec::foundation().epoch_length;
+        let produce_slots = vec![Slot::new(2 * epoch_length - 1), Slot::new(2 * epoch_length)];
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_duties(produce_slots.clone())
+            .build();
+        block_producer.production_lead = Duration::from_millis(500);
+
+        // The Beacon Node fails to produce the block of the present slot.
+        mocks
+            .beacon_node
+            .produce_result
+            .write()
+            .unwrap()
+            .pop_front();
+        mocks
+            .beacon_node
+            .push_produce_failure(BeaconNodeError::Timeout);
+
+        let slot_duration = Duration::from_secs(mocks.spec.slot_duration);
+        mocks.slot_clock.set_slot(produce_slots[0].as_u64());
+        mocks
+            .slot_clock
+            .set_duration_into_slot(slot_duration - Duration::from_millis(100));
+
+        // The error does not prevent the next slot being produced...
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slots[1],
+                PublishOutcome::ValidBlock
+            ))
+        );
+        // ...and the present slot is not polled again.
+        assert_eq!(
+            block_producer.poll_with_lead(),
+            Ok(PollOutcome::SlotAlreadyProcessed(produce_slots[1]))
+        );
+    }
+
+    #[test]
+    pub fn no_block_available() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let BlockProducerMocks {
+            slot_clock,
+            beacon_node,
+            produce_slot,
+            ..
+        } = mocks;
+        slot_clock.set_slot(produce_slot.as_u64());
+
+        let cases = vec![
+            (
+                NoBlockReason::Syncing,
+                PollOutcome::BeaconNodeSyncing(produce_slot),
+            ),
+            (
+                NoBlockReason::MissingParent,
+                PollOutcome::BeaconNodeMissingParent(produce_slot),
+            ),
+            (
+                NoBlockReason::Unspecified,
+                PollOutcome::BeaconNodeUnableToProduceBlock(produce_slot),
+            ),
+        ];
+
+        for (reason, outcome) in cases {
+            beacon_node.set_next_produce_result(Ok(ProduceOutcome::NoBlockAvailable(reason)));
+            block_producer.last_processed_slot = None;
+            assert_eq!(block_producer.poll(), Ok(outcome));
+        }
+
+        assert!(beacon_node.publish_input.read().unwrap().is_none());
+
+        // A failure of the Beacon Node is an error, rather than an outcome.
+        beacon_node.set_next_produce_result(Err(BeaconNodeError::RemoteFailure("".to_string())));
+        block_producer.last_processed_slot = None;
+        assert_eq!(
+            block_producer.poll(),
+            Err(Error::BeaconNodeError(BeaconNodeError::RemoteFailure(
+                "".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    pub fn queued_produce_results() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let spec = ChainSpec::foundation();
+        let produce_slots: Vec<Slot> = (1..4).map(|i| Slot::new(i * spec.epoch_length)).collect();
+
+        let (mut block_producer, mocks) = BlockProducerTester::new().with_spec(spec).build();
+        let BlockProducerMocks {
+            spec,
+            slot_clock,
+            beacon_node,
+            ..
+        } = mocks;
+
+        // Produce at the first slot of three consecutive epochs.
+        let mut epoch_map = EpochMap::new(spec.epoch_length);
+        for slot in &produce_slots {
+            epoch_map.map.insert(slot.epoch(spec.epoch_length), *slot);
+        }
+        block_producer.epoch_map = Arc::new(epoch_map);
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slots[2];
+        beacon_node
+            .set_next_produce_result(Ok(ProduceOutcome::NoBlockAvailable(NoBlockReason::Syncing)));
+        beacon_node.push_produce_result(Err(BeaconNodeError::DecodeFailure));
+        beacon_node.push_produce_result(Ok(ProduceOutcome::Block(block)));
+
+        let expected = vec![
+            Ok(PollOutcome::BeaconNodeSyncing(produce_slots[0])),
+            Err(Error::BeaconNodeError(BeaconNodeError::DecodeFailure)),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slots[2],
+                PublishOutcome::ValidBlock,
+            )),
+        ];
+
+        for (slot, result) in produce_slots.iter().zip(expected) {
+            slot_clock.set_slot(slot.as_u64());
+            assert_eq!(block_producer.poll(), result);
+        }
+
+        // The last result repeats once the queue is exhausted.
+        block_producer.last_processed_slot = None;
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlashableBlockNotProduced(produce_slots[2]))
+        );
+    }
+
+    #[test]
+    pub fn published_block_rejected_as_slashable() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let BlockProducerMocks {
+            keypair,
+            slot_clock,
+            beacon_node,
+            slashing_protection,
+            produce_slot,
+            ..
+        } = mocks;
+
+        beacon_node.set_next_publish_result(Ok(PublishOutcome::RejectedSlashable));
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::PublishedBlockRejectedAsSlashable(produce_slot))
+        );
+        assert!(slashing_protection.is_slashable_block(&keypair.pk, produce_slot));
+
+        // Future polls refuse to sign at the slot.
+        block_producer.last_processed_slot = None;
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlashableBlockNotProduced(produce_slot))
+        );
+    }
+
+    #[test]
+    pub fn recover_poisoned_slot_clock() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        mocks.slot_clock.poison();
+        assert_eq!(block_producer.poll(), Err(Error::SlotClockPoisoned));
+
+        block_producer.recover_poisoned = true;
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+    }
+
+    #[test]
+    pub fn try_new_rejects_zero_epoch_length() {
+        let mut spec = ChainSpec::foundation();
+        spec.epoch_length = 0;
+
+        let result = BlockProducer::try_new(
+            Arc::new(spec),
+            Keypair::random().pk,
+            Arc::new(EpochMap::new(1)),
+            Arc::new(TestingSlotClock::new(0)),
+            Arc::new(SimulatedBeaconNode::default()),
+            Arc::new(LocalSigner::new(Keypair::random())),
+            Arc::new(InMemorySlashingProtection::default()),
+            null_logger(),
+        );
+        assert_eq!(result.err(), Some(Error::EpochLengthIsZero));
+
+        let (_, mocks) = BlockProducerTester::new().build();
+        assert!(BlockProducer::try_new(
+            mocks.spec,
+            mocks.keypair.pk,
+            mocks.epoch_map,
+            mocks.slot_clock,
+            mocks.beacon_node,
+            mocks.signer,
+            mocks.slashing_protection,
+            null_logger(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    pub fn duration_to_next_slot() {
+        let (block_producer, mocks) = BlockProducerTester::new().build();
+        let slot_duration = Duration::from_secs(mocks.spec.slot_duration);
+
+        assert_eq!(block_producer.duration_to_next_slot(), Some(slot_duration));
+
+        mocks
+            .slot_clock
+            .set_duration_into_slot(Duration::from_millis(1_500));
+        assert_eq!(
+            block_producer.duration_to_next_slot(),
+            Some(slot_duration - Duration::from_millis(1_500))
+        );
+
+        // A clock which disagrees with the spec never results in a negative duration.
+        mocks
+            .slot_clock
+            .set_duration_into_slot(slot_duration + Duration::from_secs(1));
+        assert_eq!(
+            block_producer.duration_to_next_slot(),
+            Some(Duration::from_secs(0))
+        );
+    }
+
+    #[test]
+    pub fn signs_for_own_pubkey() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        assert_eq!(mocks.signer.signed_for(), vec![]);
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        // Once for the randao reveal and once for the block proposal.
+        assert_eq!(
+            mocks.signer.signed_for(),
+            vec![mocks.keypair.pk.clone(), mocks.keypair.pk.clone()]
+        );
+    }
+
+    #[test]
+    pub fn signature_mismatch() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.verify_own_signatures = true;
+
+        // The tester's signer holds a different key to the producer's `pubkey`.
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SignatureMismatch(mocks.produce_slot))
+        );
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+    }
+
+    #[test]
+    pub fn reset_processed_slot() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_beacon_node_failure(BeaconNodeError::DecodeFailure)
+            .build();
+        let produce_slot = mocks.produce_slot;
+
+        // The Beacon Node fails, so the slot is processed without a block being signed.
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert!(block_producer.poll().is_err());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlotAlreadyProcessed(produce_slot))
+        );
+
+        // Resetting from a later slot has no effect.
+        assert_eq!(
+            block_producer.reset_processed_slot(produce_slot + 1),
+            Ok(())
+        );
+        assert_eq!(block_producer.last_processed_slot, Some(produce_slot));
+
+        assert_eq!(block_producer.reset_processed_slot(produce_slot), Ok(()));
+        assert_eq!(block_producer.last_processed_slot, Some(produce_slot - 1));
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        mocks
+            .beacon_node
+            .set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        // A block has now been signed at the slot, so it may not be reset.
+        assert_eq!(
+            block_producer.reset_processed_slot(produce_slot),
+            Err(Error::ResetWouldBeSlashable)
+        );
+        assert_eq!(
+            block_producer.reset_processed_slot(produce_slot - 10),
+            Err(Error::ResetWouldBeSlashable)
+        );
+        assert_eq!(block_producer.last_processed_slot, Some(produce_slot));
+    }
+
+    #[test]
+    pub fn require_synced() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.require_synced = true;
+        let produce_slot = mocks.produce_slot;
+
+        mocks.beacon_node.set_next_health_result(Ok(NodeHealth {
+            is_syncing: true,
+            head_slot: produce_slot - 50,
+        }));
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BeaconNodeSyncing(produce_slot))
+        );
+        assert!(mocks.beacon_node.produce_input.read().unwrap().is_none());
+
+        // Once synced, the block is produced at the next opportunity.
+        block_producer.last_processed_slot = None;
+        mocks.beacon_node.set_next_health_result(Ok(NodeHealth {
+            is_syncing: false,
+            head_slot: produce_slot - 1,
+        }));
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+    }
+
+    #[test]
+    pub fn validator_status_unsupported() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let produce_slot = mocks.produce_slot;
+
+        // As with the gRPC client, the node does not support the validator status request.
+        mocks
+            .beacon_node
+            .set_next_validator_status_result(Err(BeaconNodeError::RemoteFailure(
+                "Validator status is not supported.".to_string(),
+            )));
+
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+        assert!(mocks
+            .beacon_node
+            .validator_status_input
+            .read()
+            .unwrap()
+            .is_none());
+
+        // The status is only requested once the check is enabled.
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.check_validator_status = true;
+        mocks
+            .beacon_node
+            .set_next_validator_status_result(Err(BeaconNodeError::RemoteFailure(
+                "Validator status is not supported.".to_string(),
+            )));
+        mocks.slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Err(Error::BeaconNodeError(BeaconNodeError::RemoteFailure(
+                "Validator status is not supported.".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    pub fn block_produced_publish_outcome() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        // Skip the first block, which is reported as `FirstBlockProduced`.
+        block_producer.has_produced_since_start = true;
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+    }
+
+    #[test]
+    pub fn block_produced_flagged_invalid() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.has_produced_since_start = true;
+        mocks
+            .beacon_node
+            .set_next_publish_result(Ok(PublishOutcome::InvalidBlock("bad".to_string())));
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::BlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::InvalidBlock("bad".to_string())
+            ))
+        );
+        // The slot is processed regardless, so the block is not produced again.
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SlotAlreadyProcessed(mocks.produce_slot))
+        );
+    }
+
+    #[test]
+    pub fn first_block_flagged_invalid() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        mocks
+            .beacon_node
+            .set_next_publish_result(Ok(PublishOutcome::InvalidBlock("bad".to_string())));
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::InvalidBlock("bad".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    pub fn max_lookahead() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        block_producer.max_lookahead = Some(Epoch::new(0));
+        let produce_epoch = mocks.produce_slot.epoch(mocks.spec.epoch_length);
+
+        // The duties of a later epoch are not queried.
+        mocks.slot_clock.set_slot(
+            (produce_epoch - 1)
+                .start_slot(mocks.spec.epoch_length)
+                .as_u64(),
+        );
+        assert_eq!(
+            block_producer.poll_slot(mocks.produce_slot),
+            Ok(PollOutcome::ProducerDutiesUnknown(mocks.produce_slot))
+        );
+        assert_eq!(block_producer.slots_since_duties_updated(), None);
+        assert!(mocks.beacon_node.produce_input.read().unwrap().is_none());
+
+        // Once the present epoch catches up, the slot is produced.
+        mocks
+            .slot_clock
+            .set_slot(produce_epoch.start_slot(mocks.spec.epoch_length).as_u64());
+        assert_eq!(
+            block_producer.poll_slot(mocks.produce_slot),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+    }
+
+    #[test]
+    pub fn timing_callback() {
+        let (mut block_producer, mocks) = BlockProducerTester::new().build();
+        let timings = Arc::new(Mutex::new(vec![]));
+        let recorded = timings.clone();
+        block_producer.timing_callback = Some(Box::new(move |timing| {
+            recorded.lock().unwrap().push(timing)
+        }));
+        mocks
+            .beacon_node
+            .set_request_delay(Duration::from_millis(10));
+
+        // No block is requested, so there is no timing.
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64() - 1);
+        block_producer.poll().unwrap();
+        assert!(timings.lock().unwrap().is_empty());
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        let timings = timings.lock().unwrap();
+        assert_eq!(timings.len(), 1);
+        assert_eq!(timings[0].slot, mocks.produce_slot);
+        assert!(timings[0].produce.unwrap() >= Duration::from_millis(10));
+        assert!(timings[0].publish.unwrap() >= Duration::from_millis(10));
+    }
+
+    #[test]
+    pub fn verify_proposal_root() {
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_keypair(Keypair::random())
+            .build();
+        block_producer.verify_proposal_root = true;
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+
+        // A fork which differs from that of the spec is honoured by the expected root too.
+        let fork = Fork {
+            pre_fork_version: 1,
+            post_fork_version: 1,
+            fork_slot: mocks.spec.genesis_slot,
+        };
+        block_producer.set_fork(fork.clone());
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::FirstBlockProduced(
+                mocks.produce_slot,
+                PublishOutcome::ValidBlock
+            ))
+        );
+
+        let published = mocks
+            .beacon_node
+            .publish_input
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap();
+        let domain = fork.get_domain(published.slot, mocks.spec.domain_proposal);
+        assert!(published.signature.verify(
+            &published.proposal_signing_root(&mocks.spec, domain)[..],
+            &mocks.keypair.pk
+        ));
+    }
+
+    #[test]
+    pub fn poll_until() {
+        // Use short epochs, so that each production slot is in its own epoch.
+        let mut spec = ChainSpec::foundation();
+        spec.epoch_length = 4;
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_spec(spec)
+            .with_duties(vec![Slot::new(2), Slot::new(4)])
+            .build();
+
+        assert_eq!(
+            block_producer.poll_until(Slot::new(5)),
+            Ok(vec![
+                (
+                    Slot::new(1),
+                    PollOutcome::BlockProductionNotRequired(Slot::new(1))
+                ),
+                (
+                    Slot::new(2),
+                    PollOutcome::FirstBlockProduced(Slot::new(2), PublishOutcome::ValidBlock)
+                ),
+                (
+                    Slot::new(3),
+                    PollOutcome::BlockProductionNotRequired(Slot::new(3))
+                ),
+                (
+                    Slot::new(4),
+                    PollOutcome::BlockProduced(Slot::new(4), PublishOutcome::ValidBlock)
+                ),
+                (
+                    Slot::new(5),
+                    PollOutcome::BlockProductionNotRequired(Slot::new(5))
+                ),
+            ])
+        );
+        mocks.slot_clock.assert_slot(Slot::new(5));
+
+        // The target has been reached.
+        assert_eq!(block_producer.poll_until(Slot::new(5)), Ok(vec![]));
+    }
 }
@@ -0,0 +1,146 @@
+use crate::traits::{DutiesReader, DutiesReaderError};
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+use types::{Epoch, Slot};
+
+/// A `DutiesReader` for a single validator, backed by an in-memory map of the block production
+/// slots of each epoch for which duties are known.
+pub struct MapDutiesReader {
+    epoch_length: u64,
+    duties: RwLock<BTreeMap<Epoch, Vec<Slot>>>,
+}
+
+impl MapDutiesReader {
+    pub fn new(epoch_length: u64) -> Self {
+        Self {
+            epoch_length,
+            duties: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Sets the block production slots of `epoch`, replacing any known duties for that epoch.
+    ///
+    /// An empty `slots` records that no blocks are to be produced during `epoch`.
+    pub fn insert_duties(&self, epoch: Epoch, slots: Vec<Slot>) -> Result<(), DutiesReaderError> {
+        self.duties
+            .write()
+            .map_err(|_| DutiesReaderError::Poisoned)?
+            .insert(epoch, slots);
+        Ok(())
+    }
+
+    /// Forgets the duties of `epoch`, so it becomes unknown.
+    pub fn clear_duties(&self, epoch: Epoch) -> Result<(), DutiesReaderError> {
+        self.duties
+            .write()
+            .map_err(|_| DutiesReaderError::Poisoned)?
+            .remove(&epoch);
+        Ok(())
+    }
+}
+
+impl DutiesReader for MapDutiesReader {
+    fn is_block_production_slot(&self, slot: Slot) -> Result<bool, DutiesReaderError> {
+        let epoch = slot
+            .checked_epoch(self.epoch_length)
+            .ok_or(DutiesReaderError::EpochLengthIsZero)?;
+
+        match self
+            .duties
+            .read()
+            .map_err(|_| DutiesReaderError::Poisoned)?
+            .get(&epoch)
+        {
+            Some(slots) => Ok(slots.contains(&slot)),
+            None => Err(DutiesReaderError::UnknownEpoch),
+        }
+    }
+
+    /// A `MapDutiesReader` only ever stores the duties of a single validator.
+    fn managed_validator_count(&self) -> Result<usize, DutiesReaderError> {
+        Ok(1)
+    }
+
+    fn duties_epoch(&self) -> Result<Option<Epoch>, DutiesReaderError> {
+        Ok(self
+            .duties
+            .read()
+            .map_err(|_| DutiesReaderError::Poisoned)?
+            .keys()
+            .next_back()
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn production_slots() {
+        let reader = MapDutiesReader::new(8);
+        reader
+            .insert_duties(Epoch::new(1), vec![Slot::new(9), Slot::new(12)])
+            .unwrap();
+
+        assert_eq!(reader.is_block_production_slot(Slot::new(9)), Ok(true));
+        assert_eq!(reader.is_block_production_slot(Slot::new(12)), Ok(true));
+        assert_eq!(reader.is_block_production_slot(Slot::new(8)), Ok(false));
+        assert_eq!(reader.is_block_production_slot(Slot::new(15)), Ok(false));
+    }
+
+    #[test]
+    fn no_production_slots() {
+        let reader = MapDutiesReader::new(8);
+        reader.insert_duties(Epoch::new(1), vec![]).unwrap();
+
+        for slot in Epoch::new(1).slot_iter(8) {
+            assert_eq!(reader.is_block_production_slot(slot), Ok(false));
+        }
+    }
+
+    #[test]
+    fn unknown_epochs() {
+        let reader = MapDutiesReader::new(8);
+        assert_eq!(
+            reader.is_block_production_slot(Slot::new(9)),
+            Err(DutiesReaderError::UnknownEpoch)
+        );
+        assert_eq!(reader.duties_epoch(), Ok(None));
+
+        reader
+            .insert_duties(Epoch::new(1), vec![Slot::new(9)])
+            .unwrap();
+        assert_eq!(
+            reader.is_block_production_slot(Slot::new(16)),
+            Err(DutiesReaderError::UnknownEpoch)
+        );
+
+        reader.clear_duties(Epoch::new(1)).unwrap();
+        assert_eq!(
+            reader.is_block_production_slot(Slot::new(9)),
+            Err(DutiesReaderError::UnknownEpoch)
+        );
+    }
+
+    #[test]
+    fn duties_epoch() {
+        let reader = MapDutiesReader::new(8);
+        for epoch in &[3, 1, 2] {
+            reader.insert_duties(Epoch::new(*epoch), vec![]).unwrap();
+        }
+
+        assert_eq!(reader.duties_epoch(), Ok(Some(Epoch::new(3))));
+        assert_eq!(reader.managed_validator_count(), Ok(1));
+    }
+
+    #[test]
+    fn zero_epoch_length() {
+        let reader = MapDutiesReader::new(0);
+
+        assert_eq!(
+            reader.is_block_production_slot(Slot::new(0)),
+            Err(DutiesReaderError::EpochLengthIsZero)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::PollOutcome;
+use prometheus::{IntCounter, IntCounterVec, Opts, Registry};
+
+/// Prometheus counters of the `PollOutcome`s of one or more `BlockProducer`s, labelled by
+/// outcome, and of their failed block relay submissions.
+///
+/// May be shared between many `BlockProducer`s to monitor all of their outcomes together.
+pub struct BlockProducerMetrics {
+    outcomes: IntCounterVec,
+    relay_failures: IntCounter,
+}
+
+impl BlockProducerMetrics {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let outcomes = IntCounterVec::new(
+            Opts::new(
+                "block_producer_poll_outcomes_total",
+                "Count of block producer poll outcomes",
+            ),
+            &["outcome"],
+        )?;
+        let relay_failures = IntCounter::new(
+            "block_producer_relay_failures_total",
+            "Count of block relay submissions which failed",
+        )?;
+
+        Ok(Self {
+            outcomes,
+            relay_failures,
+        })
+    }
+
+    /// Registers the counters with the `registry`, so they are included when it is gathered.
+    pub fn register(&self, registry: &Registry) -> Result<(), prometheus::Error> {
+        registry.register(Box::new(self.outcomes.clone()))?;
+        registry.register(Box::new(self.relay_failures.clone()))
+    }
+
+    /// Increments the counter for the `outcome`.
+    pub fn observe(&self, outcome: &PollOutcome) {
+        self.outcomes
+            .with_label_values(&[outcome_label(outcome)])
+            .inc();
+    }
+
+    /// Returns the number of times an outcome with the given `label` has been observed.
+    pub fn outcome_count(&self, label: &str) -> i64 {
+        self.outcomes.with_label_values(&[label]).get()
+    }
+
+    /// Increments the counter of failed block relay submissions.
+    pub fn observe_relay_failure(&self) {
+        self.relay_failures.inc();
+    }
+
+    /// Returns the number of failed block relay submissions which have been observed.
+    pub fn relay_failure_count(&self) -> i64 {
+        self.relay_failures.get()
+    }
+}
+
+/// Returns the label under which the `outcome` is counted.
+pub fn outcome_label(outcome: &PollOutcome) -> &'static str {
+    match outcome {
+        PollOutcome::FirstBlockProduced(_, _) => "first_block_produced",
+        PollOutcome::BlockProduced(_, _) => "block_produced",
+        PollOutcome::BlockProducedMinimal(_, _) => "block_produced_minimal",
+        PollOutcome::SlashableBlockNotProduced(_) => "slashable_block_not_produced",
+        PollOutcome::BlockProductionNotRequired(_) => "block_production_not_required",
+        PollOutcome::ProducerDutiesUnknown(_) => "producer_duties_unknown",
+        PollOutcome::SlotAlreadyProcessed(_) => "slot_already_processed",
+        PollOutcome::SlotMissed(_) => "slot_missed",
+        PollOutcome::ProposalMissed(_) => "proposal_missed",
+        PollOutcome::BeaconNodeUnableToProduceBlock(_) => "beacon_node_unable_to_produce_block",
+        PollOutcome::BeaconNodeSyncing(_) => "beacon_node_syncing",
+        PollOutcome::BeaconNodeMissingParent(_) => "beacon_node_missing_parent",
+        PollOutcome::BeaconNodeReturnedMalformedBlock(_) => "beacon_node_returned_malformed_block",
+        PollOutcome::RandaoRevealMismatch(_) => "randao_reveal_mismatch",
+        PollOutcome::SignerLocked(_) => "signer_locked",
+        PollOutcome::SignerUnknownKey(_) => "signer_unknown_key",
+        PollOutcome::SignerRejection(_, _) => "signer_rejection",
+        PollOutcome::SignatureMismatch(_) => "signature_mismatch",
+        PollOutcome::ValidatorIsUnknown(_) => "validator_is_unknown",
+        PollOutcome::ValidatorPendingActivation(_) => "validator_pending_activation",
+        PollOutcome::InsufficientPeers(_) => "insufficient_peers",
+        PollOutcome::QuietWindow(_) => "quiet_window",
+        PollOutcome::Rebroadcast(_) => "rebroadcast",
+        PollOutcome::PublishedBlockRejectedAsSlashable(_) => {
+            "published_block_rejected_as_slashable"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PublishOutcome;
+    use types::Slot;
+
+    #[test]
+    fn observe() {
+        let metrics = BlockProducerMetrics::new().unwrap();
+
+        metrics.observe(&PollOutcome::BlockProduced(
+            Slot::new(1),
+            PublishOutcome::ValidBlock,
+        ));
+        metrics.observe(&PollOutcome::BlockProduced(
+            Slot::new(2),
+            PublishOutcome::InvalidBlock("".to_string()),
+        ));
+        metrics.observe(&PollOutcome::SignerRejection(Slot::new(3), "".to_string()));
+
+        assert_eq!(metrics.outcome_count("block_produced"), 2);
+        assert_eq!(metrics.outcome_count("signer_rejection"), 1);
+        assert_eq!(metrics.outcome_count("slashable_block_not_produced"), 0);
+    }
+
+    #[test]
+    fn observe_relay_failure() {
+        let metrics = BlockProducerMetrics::new().unwrap();
+        assert_eq!(metrics.relay_failure_count(), 0);
+
+        metrics.observe_relay_failure();
+        metrics.observe_relay_failure();
+        assert_eq!(metrics.relay_failure_count(), 2);
+    }
+
+    #[test]
+    fn register() {
+        let registry = Registry::new();
+        let metrics = BlockProducerMetrics::new().unwrap();
+
+        assert!(metrics.register(&registry).is_ok());
+        // The same counters may not be registered twice.
+        assert!(metrics.register(&registry).is_err());
+    }
+}
@@ -0,0 +1,437 @@
+use crate::{
+    BeaconNode, BlockProducer, DutiesReader, Error, PollOutcome, PollStep, Signer, SignerError,
+    SlashingProtection, UnsignedBlock,
+};
+use slot_clock::SlotClock;
+use std::sync::Arc;
+use types::{PublicKey, Signature};
+
+/// Performs block production duties for many validators, each with their own `BlockProducer`.
+///
+/// The slot clock is read once per poll and each validator is then polled at that slot. Each
+/// validator tracks its own processed slots, so an error for one validator does not affect the
+/// others.
+///
+/// The blocks produced at each slot are signed with a single `sign_block_proposal_batch` request
+/// to each distinct signer, rather than a request per validator. Validators with different
+/// signers cannot share a request, so a slot may require more than one.
+pub struct MultiBlockProducer<
+    T: SlotClock,
+    U: BeaconNode,
+    V: DutiesReader,
+    W: Signer,
+    X: SlashingProtection,
+> {
+    slot_clock: Arc<T>,
+    producers: Vec<BlockProducer<T, U, V, W, X>>,
+}
+
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection>
+    MultiBlockProducer<T, U, V, W, X>
+{
+    /// Returns a new instance which reads the present slot from `slot_clock`.
+    pub fn new(slot_clock: Arc<T>, producers: Vec<BlockProducer<T, U, V, W, X>>) -> Self {
+        Self {
+            slot_clock,
+            producers,
+        }
+    }
+
+    /// Add a `BlockProducer` for another validator.
+    pub fn push(&mut self, producer: BlockProducer<T, U, V, W, X>) {
+        self.producers.push(producer);
+    }
+
+    /// Returns the `BlockProducer` for each validator.
+    pub fn producers(&self) -> &[BlockProducer<T, U, V, W, X>] {
+        &self.producers
+    }
+
+    /// "Poll" each validator to see if they are required to take any action.
+    ///
+    /// The slot clock is read once, then each validator is polled at that slot. Returns the result
+    /// of each validator's poll, in the order the validators were added.
+    pub fn poll(&mut self) -> Result<Vec<(PublicKey, Result<PollOutcome, Error>)>, Error> {
+        let slot = self
+            .slot_clock
+            .present_slot()
+            .map_err(|_| Error::SlotClockError)?
+            .ok_or(Error::SlotUnknowable)?;
+
+        let steps: Vec<PollStep> = self
+            .producers
+            .iter_mut()
+            .map(|producer| producer.start_poll_slot(slot))
+            .collect();
+        let signatures = self.sign_in_batches(&steps);
+
+        Ok(self
+            .producers
+            .iter_mut()
+            .zip(steps.into_iter().zip(signatures))
+            .map(|(producer, (step, signature))| {
+                let result = match step {
+                    PollStep::Done(result) => result,
+                    PollStep::Unsigned(unsigned) => {
+                        let signature = signature.unwrap_or_else(|| {
+                            Err(SignerError::Backend(
+                                "No signature returned in batch.".to_string(),
+                            ))
+                        });
+                        producer.finish_poll_slot(unsigned, signature)
+                    }
+                };
+                (producer.pubkey().clone(), result)
+            })
+            .collect())
+    }
+
+    /// Signs the unsigned block (if any) of each of the `steps`, with a single request to each
+    /// distinct signer. Returns the signature for each step, in order.
+    fn sign_in_batches(&self, steps: &[PollStep]) -> Vec<Option<Result<Signature, SignerError>>> {
+        let mut signatures: Vec<_> = steps.iter().map(|_| None).collect();
+
+        let mut unsigned: Vec<(usize, &UnsignedBlock)> = steps
+            .iter()
+            .enumerate()
+            .filter_map(|(i, step)| match step {
+                PollStep::Unsigned(unsigned) => Some((i, unsigned)),
+                PollStep::Done(_) => None,
+            })
+            .collect();
+
+        while let Some(&(first, _)) = unsigned.first() {
+            let signer = self.producers[first].signer();
+            let (batch, rest): (Vec<_>, Vec<_>) = unsigned
+                .into_iter()
+                .partition(|(i, _)| Arc::ptr_eq(self.producers[*i].signer(), signer));
+
+            let messages: Vec<(&PublicKey, &[u8])> = batch
+                .iter()
+                .map(|(i, unsigned)| (self.producers[*i].pubkey(), &unsigned.root[..]))
+                .collect();
+            let results = signer.sign_block_proposal_batch(&messages);
+
+            for ((i, _), result) in batch.iter().zip(results) {
+                signatures[*i] = Some(result);
+            }
+            unsigned = rest;
+        }
+
+        signatures
+    }
+
+    /// "Poll" each validator, as per `poll`, returning only the results which are worth logging
+    /// or acting upon.
+    ///
+    /// Routine outcomes (see `PollOutcome::is_actionable`) are omitted, errors are always
+    /// returned.
+    pub fn poll_actionable(
+        &mut self,
+    ) -> Result<Vec<(PublicKey, Result<PollOutcome, Error>)>, Error> {
+        Ok(self
+            .poll()?
+            .into_iter()
+            .filter(|(_, result)| match result {
+                Ok(outcome) => outcome.is_actionable(),
+                Err(_) => true,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{null_logger, EpochMap, LocalSigner, SimulatedBeaconNode};
+    use crate::{InMemorySlashingProtection, ProduceOutcome, PublishOutcome, ValidatorStatus};
+    use slot_clock::TestingSlotClock;
+    use types::{
+        test_utils::{SeedableRng, TestRandom, XorShiftRng},
+        BeaconBlock, ChainSpec, Keypair, Slot,
+    };
+
+    #[test]
+    pub fn polling_two_validators() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let beacon_node = Arc::new(SimulatedBeaconNode::default());
+        let slashing_protection = Arc::new(InMemorySlashingProtection::default());
+
+        let produce_slot = Slot::new(100);
+        let produce_epoch = produce_slot.epoch(spec.epoch_length);
+
+        let producer = |keypair: &Keypair, slot: Slot| {
+            let mut epoch_map = EpochMap::new(spec.epoch_length);
+            epoch_map.map.insert(produce_epoch, slot);
+
+            let mut block_producer = BlockProducer::new(
+                spec.clone(),
+                keypair.pk.clone(),
+                Arc::new(epoch_map),
+                slot_clock.clone(),
+                beacon_node.clone(),
+                Arc::new(LocalSigner::new(keypair.clone())),
+                slashing_protection.clone(),
+                null_logger(),
+            );
+            block_producer.check_validator_status = true;
+            block_producer
+        };
+
+        // Only the first validator is required to produce at `produce_slot`.
+        let producing = Keypair::random();
+        let idle = Keypair::random();
+        let mut multi_block_producer = MultiBlockProducer::new(
+            slot_clock.clone(),
+            vec![
+                producer(&producing, produce_slot),
+                producer(&idle, produce_slot + 1),
+            ],
+        );
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        beacon_node.set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+        beacon_node.set_next_publish_result(Ok(PublishOutcome::ValidBlock));
+        beacon_node.set_next_validator_status_result(Ok(ValidatorStatus::Active));
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            multi_block_producer.poll(),
+            Ok(vec![
+                (
+                    producing.pk.clone(),
+                    Ok(PollOutcome::FirstBlockProduced(
+                        produce_slot,
+                        PublishOutcome::ValidBlock
+                    ))
+                ),
+                (
+                    idle.pk.clone(),
+                    Ok(PollOutcome::BlockProductionNotRequired(produce_slot))
+                ),
+            ])
+        );
+        assert_eq!(
+            *beacon_node.validator_status_input.read().unwrap(),
+            Some(producing.pk.clone())
+        );
+
+        // Trying the same slot again...
+        assert_eq!(
+            multi_block_producer.poll(),
+            Ok(vec![
+                (
+                    producing.pk.clone(),
+                    Ok(PollOutcome::SlotAlreadyProcessed(produce_slot))
+                ),
+                (
+                    idle.pk.clone(),
+                    Ok(PollOutcome::BlockProductionNotRequired(produce_slot))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn signing_in_a_batch() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let beacon_node = Arc::new(SimulatedBeaconNode::default());
+        let slashing_protection = Arc::new(InMemorySlashingProtection::default());
+        let shared_signer = Arc::new(LocalSigner::new(Keypair::random()));
+        let other_signer = Arc::new(LocalSigner::new(Keypair::random()));
+
+        let produce_slot = Slot::new(100);
+        let produce_epoch = produce_slot.epoch(spec.epoch_length);
+
+        let producer = |keypair: &Keypair, slot: Slot, signer: &Arc<LocalSigner>| {
+            let mut epoch_map = EpochMap::new(spec.epoch_length);
+            epoch_map.map.insert(produce_epoch, slot);
+
+            BlockProducer::new(
+                spec.clone(),
+                keypair.pk.clone(),
+                Arc::new(epoch_map),
+                slot_clock.clone(),
+                beacon_node.clone(),
+                signer.clone(),
+                slashing_protection.clone(),
+                null_logger(),
+            )
+        };
+
+        // Three of the four validators produce at `produce_slot`, two of which share a signer.
+        let keypairs: Vec<Keypair> = (0..4).map(|_| Keypair::random()).collect();
+        let mut multi_block_producer = MultiBlockProducer::new(
+            slot_clock.clone(),
+            vec![
+                producer(&keypairs[0], produce_slot, &shared_signer),
+                producer(&keypairs[1], produce_slot + 1, &shared_signer),
+                producer(&keypairs[2], produce_slot, &other_signer),
+                producer(&keypairs[3], produce_slot, &shared_signer),
+            ],
+        );
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        beacon_node.set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+        beacon_node.set_next_publish_result(Ok(PublishOutcome::ValidBlock));
+        beacon_node.set_next_validator_status_result(Ok(ValidatorStatus::Active));
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        let outcomes: Vec<Result<PollOutcome, Error>> = multi_block_producer
+            .poll()
+            .unwrap()
+            .into_iter()
+            .map(|(_, result)| result)
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                Ok(PollOutcome::FirstBlockProduced(
+                    produce_slot,
+                    PublishOutcome::ValidBlock
+                )),
+                Ok(PollOutcome::BlockProductionNotRequired(produce_slot)),
+                Ok(PollOutcome::FirstBlockProduced(
+                    produce_slot,
+                    PublishOutcome::ValidBlock
+                )),
+                Ok(PollOutcome::FirstBlockProduced(
+                    produce_slot,
+                    PublishOutcome::ValidBlock
+                )),
+            ]
+        );
+
+        // One request per signer, the shared signer signing two proposals.
+        assert_eq!(shared_signer.batch_sizes(), vec![2]);
+        assert_eq!(other_signer.batch_sizes(), vec![1]);
+
+        // Nothing is signed when no blocks are required.
+        multi_block_producer.poll().unwrap();
+        assert_eq!(shared_signer.batch_sizes(), vec![2]);
+    }
+
+    #[test]
+    pub fn error_does_not_affect_other_validators() {
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let slashing_protection = Arc::new(InMemorySlashingProtection::default());
+
+        let produce_slot = Slot::new(100);
+        let produce_epoch = produce_slot.epoch(spec.epoch_length);
+
+        let producer = |keypair: &Keypair, beacon_node: Arc<SimulatedBeaconNode>| {
+            let mut epoch_map = EpochMap::new(spec.epoch_length);
+            epoch_map.map.insert(produce_epoch, produce_slot + 1);
+
+            BlockProducer::new(
+                spec.clone(),
+                keypair.pk.clone(),
+                Arc::new(epoch_map),
+                slot_clock.clone(),
+                beacon_node,
+                Arc::new(LocalSigner::new(keypair.clone())),
+                slashing_protection.clone(),
+                null_logger(),
+            )
+        };
+
+        // The second validator's Beacon Node reports an epoch length which disagrees with the spec.
+        let healthy = Keypair::random();
+        let erroring = Keypair::random();
+        let erroring_node = Arc::new(SimulatedBeaconNode::default());
+        erroring_node.set_next_epoch_length_result(Ok(Some(spec.epoch_length + 1)));
+        let mut multi_block_producer = MultiBlockProducer::new(
+            slot_clock.clone(),
+            vec![
+                producer(&healthy, Arc::new(SimulatedBeaconNode::default())),
+                producer(&erroring, erroring_node),
+            ],
+        );
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            multi_block_producer.poll(),
+            Ok(vec![
+                (
+                    healthy.pk.clone(),
+                    Ok(PollOutcome::BlockProductionNotRequired(produce_slot))
+                ),
+                (erroring.pk.clone(), Err(Error::EpochLengthMismatch)),
+            ])
+        );
+        assert_eq!(
+            multi_block_producer.producers()[1].last_processed_slot,
+            None
+        );
+    }
+
+    #[test]
+    pub fn poll_actionable() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let spec = Arc::new(ChainSpec::foundation());
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let beacon_node = Arc::new(SimulatedBeaconNode::default());
+        let slashing_protection = Arc::new(InMemorySlashingProtection::default());
+
+        let produce_slot = Slot::new(100);
+        let produce_epoch = produce_slot.epoch(spec.epoch_length);
+
+        let producer = |keypair: &Keypair, slot: Slot| {
+            let mut epoch_map = EpochMap::new(spec.epoch_length);
+            epoch_map.map.insert(produce_epoch, slot);
+
+            BlockProducer::new(
+                spec.clone(),
+                keypair.pk.clone(),
+                Arc::new(epoch_map),
+                slot_clock.clone(),
+                beacon_node.clone(),
+                Arc::new(LocalSigner::new(keypair.clone())),
+                slashing_protection.clone(),
+                null_logger(),
+            )
+        };
+
+        // Only the second of three validators is required to produce at `produce_slot`.
+        let keypairs: Vec<Keypair> = (0..3).map(|_| Keypair::random()).collect();
+        let mut multi_block_producer = MultiBlockProducer::new(
+            slot_clock.clone(),
+            vec![
+                producer(&keypairs[0], produce_slot + 1),
+                producer(&keypairs[1], produce_slot),
+                producer(&keypairs[2], produce_slot + 2),
+            ],
+        );
+
+        let mut block = BeaconBlock::random_for_test(&mut rng);
+        block.slot = produce_slot;
+        beacon_node.set_next_produce_result(Ok(ProduceOutcome::Block(block)));
+        beacon_node.set_next_publish_result(Ok(PublishOutcome::ValidBlock));
+        beacon_node.set_next_validator_status_result(Ok(ValidatorStatus::Active));
+
+        slot_clock.set_slot(produce_slot.as_u64());
+        assert_eq!(
+            multi_block_producer.poll_actionable(),
+            Ok(vec![(
+                keypairs[1].pk.clone(),
+                Ok(PollOutcome::FirstBlockProduced(
+                    produce_slot,
+                    PublishOutcome::ValidBlock
+                ))
+            )])
+        );
+
+        // Trying the same slot again, nothing is actionable...
+        assert_eq!(multi_block_producer.poll_actionable(), Ok(vec![]));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::slashing_protection::pubkey_key;
+use crate::traits::{ProcessLock, ProcessLockError};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::sync::RwLock;
+use types::PublicKey;
+
+/// Holds locks in memory only, so they only exclude other producers in the same process.
+///
+/// Should only be used for testing.
+#[derive(Default)]
+pub struct InMemoryProcessLock {
+    held: RwLock<HashSet<String>>,
+}
+
+impl ProcessLock for InMemoryProcessLock {
+    fn acquire(&self, pubkey: &PublicKey) -> Result<(), ProcessLockError> {
+        let mut held = self.held.write().map_err(|_| ProcessLockError::Poisoned)?;
+        if held.insert(pubkey_key(pubkey)) {
+            Ok(())
+        } else {
+            Err(ProcessLockError::AlreadyHeld)
+        }
+    }
+
+    fn release(&self, pubkey: &PublicKey) -> Result<(), ProcessLockError> {
+        let mut held = self.held.write().map_err(|_| ProcessLockError::Poisoned)?;
+        held.remove(&pubkey_key(pubkey));
+        Ok(())
+    }
+}
+
+/// Holds an advisory lock for each validator by creating a `<pubkey_hex>.lock` file (containing
+/// the process id) in some directory.
+///
+/// The lock file is removed when the lock is released. If a process exits without releasing its
+/// locks (e.g., it crashed), the lock files must be removed manually.
+pub struct FileProcessLock {
+    dir: PathBuf,
+}
+
+impl FileProcessLock {
+    /// Returns a new instance which stores lock files in `dir`, creating `dir` if required.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Result<Self, ProcessLockError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .map_err(|e| ProcessLockError::StorageFailure(format!("{:?}", e)))?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, pubkey: &PublicKey) -> PathBuf {
+        self.dir.join(format!("{}.lock", pubkey_key(pubkey)))
+    }
+}
+
+impl ProcessLock for FileProcessLock {
+    /// If the process id cannot be written, the lock file is removed so the lock is not left
+    /// held.
+    fn acquire(&self, pubkey: &PublicKey) -> Result<(), ProcessLockError> {
+        let path = self.path(pubkey);
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| match e.kind() {
+                ErrorKind::AlreadyExists => ProcessLockError::AlreadyHeld,
+                _ => ProcessLockError::StorageFailure(format!("{:?}", e)),
+            })?;
+
+        writeln!(file, "{}", std::process::id()).map_err(|e| {
+            drop(file);
+            let _ = fs::remove_file(&path);
+            ProcessLockError::StorageFailure(format!("{:?}", e))
+        })
+    }
+
+    fn release(&self, pubkey: &PublicKey) -> Result<(), ProcessLockError> {
+        fs::remove_file(self.path(pubkey))
+            .map_err(|e| ProcessLockError::StorageFailure(format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::Keypair;
+
+    #[test]
+    fn in_memory() {
+        let lock = InMemoryProcessLock::default();
+        let pubkey = Keypair::random().pk;
+        let other_pubkey = Keypair::random().pk;
+
+        assert_eq!(lock.acquire(&pubkey), Ok(()));
+        assert_eq!(lock.acquire(&pubkey), Err(ProcessLockError::AlreadyHeld));
+        // Locks are per-validator.
+        assert_eq!(lock.acquire(&other_pubkey), Ok(()));
+
+        lock.release(&pubkey).unwrap();
+        assert_eq!(lock.acquire(&pubkey), Ok(()));
+    }
+
+    #[test]
+    fn file() {
+        let dir = std::env::temp_dir().join(format!(
+            "block_producer_process_lock_test_{}",
+            std::process::id()
+        ));
+        let pubkey = Keypair::random().pk;
+
+        // Separate instances share the lock files, as separate processes would.
+        let lock = FileProcessLock::new(&dir).unwrap();
+        let other_lock = FileProcessLock::new(&dir).unwrap();
+
+        assert_eq!(lock.acquire(&pubkey), Ok(()));
+        assert_eq!(
+            other_lock.acquire(&pubkey),
+            Err(ProcessLockError::AlreadyHeld)
+        );
+
+        lock.release(&pubkey).unwrap();
+        assert_eq!(other_lock.acquire(&pubkey), Ok(()));
+        other_lock.release(&pubkey).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
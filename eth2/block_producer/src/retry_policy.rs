@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+/// Describes how often a request to the Beacon Node which fails with a retryable error should be
+/// attempted, with an exponentially increasing delay between attempts.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first. A request is always attempted once.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for each subsequent retry.
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+        }
+    }
+
+    /// Returns the delay to wait after the given failed `attempt` (where the first attempt is
+    /// `1`) before attempting again.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.saturating_sub(1).min(31);
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or_else(|| Duration::from_secs(u64::max_value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_delay() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(4), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn delay_does_not_overflow() {
+        let policy = RetryPolicy::new(100, Duration::from_secs(u64::max_value() / 2));
+
+        assert_eq!(policy.delay(100), Duration::from_secs(u64::max_value()));
+    }
+}
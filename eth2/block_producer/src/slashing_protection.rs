@@ -0,0 +1,323 @@
+use crate::traits::{SlashingProtection, SlashingProtectionError};
+use serde_json::Value;
+use ssz::ssz_encode;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::RwLock;
+use types::{Hash256, PublicKey, Slot};
+
+/// Returns a hex string of the SSZ encoding of the `pubkey`, used as a storage key.
+pub(crate) fn pubkey_key(pubkey: &PublicKey) -> String {
+    ssz_encode(pubkey)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Records the highest slot at which each validator has signed a block, in memory only.
+///
+/// Records are lost when the process exits, so this should only be used for testing.
+#[derive(Default)]
+pub struct InMemorySlashingProtection {
+    highest_signed_slots: RwLock<HashMap<String, Slot>>,
+}
+
+impl SlashingProtection for InMemorySlashingProtection {
+    fn is_slashable_block(&self, pubkey: &PublicKey, slot: Slot) -> bool {
+        match self.highest_signed_slots.read() {
+            Ok(map) => match map.get(&pubkey_key(pubkey)) {
+                Some(highest) => slot <= *highest,
+                None => false,
+            },
+            // Fail safe: if the records are unreadable, assume all blocks are slashable.
+            Err(_) => true,
+        }
+    }
+
+    fn record_block_proposal(
+        &self,
+        pubkey: &PublicKey,
+        slot: Slot,
+    ) -> Result<(), SlashingProtectionError> {
+        let mut map = self
+            .highest_signed_slots
+            .write()
+            .map_err(|_| SlashingProtectionError::Poisoned)?;
+        let highest = map.entry(pubkey_key(pubkey)).or_insert(slot);
+        if slot > *highest {
+            *highest = slot;
+        }
+        Ok(())
+    }
+}
+
+/// Records the highest slot at which each validator has signed a block in an append-only file.
+///
+/// Each record is flushed and synced to disk before `record_block_proposal` returns, so a
+/// signature is never produced for a block which has not been durably recorded.
+pub struct FileSlashingProtection {
+    file: RwLock<File>,
+    records: InMemorySlashingProtection,
+}
+
+impl FileSlashingProtection {
+    /// Opens (or creates) the records file at `path`, loading any existing records.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SlashingProtectionError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)
+            .map_err(|e| SlashingProtectionError::StorageFailure(format!("{:?}", e)))?;
+
+        let records = InMemorySlashingProtection::default();
+        {
+            let mut map = records
+                .highest_signed_slots
+                .write()
+                .map_err(|_| SlashingProtectionError::Poisoned)?;
+
+            for line in BufReader::new(&file).lines() {
+                let line =
+                    line.map_err(|e| SlashingProtectionError::StorageFailure(format!("{:?}", e)))?;
+                let (key, slot) = parse_record(&line)?;
+                let highest = map.entry(key).or_insert(slot);
+                if slot > *highest {
+                    *highest = slot;
+                }
+            }
+        }
+
+        Ok(Self {
+            file: RwLock::new(file),
+            records,
+        })
+    }
+}
+
+/// Parses a `"<pubkey_hex> <slot>"` record.
+fn parse_record(line: &str) -> Result<(String, Slot), SlashingProtectionError> {
+    let mut parts = line.split_whitespace();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(key), Some(slot), None) => {
+            let slot = slot.parse::<u64>().map_err(|_| {
+                SlashingProtectionError::StorageFailure(format!("Invalid record: {}", line))
+            })?;
+            Ok((key.to_string(), Slot::new(slot)))
+        }
+        _ => Err(SlashingProtectionError::StorageFailure(format!(
+            "Invalid record: {}",
+            line
+        ))),
+    }
+}
+
+/// The interchange format version of EIP-3076 which may be audited.
+const INTERCHANGE_FORMAT_VERSION: &str = "5";
+
+/// A block proposal, recorded in an interchange file, which would prevent a new proposal.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SlotConflict {
+    pub slot: Slot,
+    /// The signing root of the proposal, if the interchange records it.
+    pub signing_root: Option<Hash256>,
+}
+
+/// The effect of importing an interchange file upon the records of a single validator.
+#[derive(Debug, PartialEq, Clone)]
+pub struct InterchangeAudit {
+    /// The recorded proposals which are not already protected against.
+    pub conflicts: Vec<SlotConflict>,
+    /// The highest recorded slot, if it is not already protected against.
+    ///
+    /// Only the highest signed slot is recorded, so importing the interchange would make this
+    /// slot and every slot before it slashable, including slots at which nothing was signed.
+    pub watermark: Option<Slot>,
+}
+
+/// Reads the EIP-3076 interchange file at `path` and returns the effect of importing it upon the
+/// records of `pubkey`, without importing it.
+///
+/// Only the `signed_blocks` of the interchange are audited; `signed_attestations` are ignored.
+pub fn audit_interchange<P: AsRef<Path>, T: SlashingProtection>(
+    path: P,
+    pubkey: &PublicKey,
+    slashing_protection: &T,
+) -> Result<InterchangeAudit, SlashingProtectionError> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| SlashingProtectionError::StorageFailure(format!("{:?}", e)))?;
+    let interchange: Value =
+        serde_json::from_str(&contents).map_err(|e| invalid_interchange(&format!("{:?}", e)))?;
+
+    let version = interchange
+        .get("metadata")
+        .and_then(|metadata| metadata.get("interchange_format_version"))
+        .and_then(Value::as_str);
+    if version != Some(INTERCHANGE_FORMAT_VERSION) {
+        return Err(invalid_interchange(
+            "unsupported interchange_format_version",
+        ));
+    }
+
+    let data = interchange
+        .get("data")
+        .and_then(Value::as_array)
+        .ok_or_else(|| invalid_interchange("missing data"))?;
+    let key = interchange_pubkey(pubkey);
+
+    let mut conflicts = vec![];
+    for record in data {
+        let record_key = record
+            .get("pubkey")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid_interchange("missing pubkey"))?;
+        if record_key.to_lowercase() != key {
+            continue;
+        }
+
+        let signed_blocks = record
+            .get("signed_blocks")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid_interchange("missing signed_blocks"))?;
+        for block in signed_blocks {
+            let conflict = parse_signed_block(block)?;
+            if !slashing_protection.is_slashable_block(pubkey, conflict.slot) {
+                conflicts.push(conflict);
+            }
+        }
+    }
+
+    let watermark = conflicts.iter().map(|conflict| conflict.slot).max();
+    Ok(InterchangeAudit {
+        conflicts,
+        watermark,
+    })
+}
+
+/// Returns `pubkey` as it appears in an interchange: the `0x`-prefixed hex of its compressed
+/// encoding.
+fn interchange_pubkey(pubkey: &PublicKey) -> String {
+    let hex: String = pubkey
+        .as_raw()
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    format!("0x{}", hex)
+}
+
+/// Parses a `{"slot": "<decimal>", "signing_root": "0x<hex>"}` signed block, where the
+/// `signing_root` is optional.
+fn parse_signed_block(block: &Value) -> Result<SlotConflict, SlashingProtectionError> {
+    let slot = block
+        .get("slot")
+        .and_then(Value::as_str)
+        .and_then(|slot| slot.parse::<u64>().ok())
+        .ok_or_else(|| invalid_interchange("invalid slot"))?;
+
+    let signing_root = match block.get("signing_root") {
+        None => None,
+        Some(root) => {
+            let root = root
+                .as_str()
+                .filter(|root| root.starts_with("0x"))
+                .and_then(|root| hex_to_bytes(&root[2..]))
+                .filter(|root| root.len() == 32)
+                .ok_or_else(|| invalid_interchange("invalid signing_root"))?;
+            Some(Hash256::from(&root[..]))
+        }
+    };
+
+    Ok(SlotConflict {
+        slot: Slot::new(slot),
+        signing_root,
+    })
+}
+
+fn invalid_interchange(reason: &str) -> SlashingProtectionError {
+    SlashingProtectionError::StorageFailure(format!("Invalid interchange: {}", reason))
+}
+
+/// Decodes a hex string, returning `None` if it is not valid hex.
+fn hex_to_bytes(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+impl SlashingProtection for FileSlashingProtection {
+    fn is_slashable_block(&self, pubkey: &PublicKey, slot: Slot) -> bool {
+        self.records.is_slashable_block(pubkey, slot)
+    }
+
+    fn record_block_proposal(
+        &self,
+        pubkey: &PublicKey,
+        slot: Slot,
+    ) -> Result<(), SlashingProtectionError> {
+        let mut file = self
+            .file
+            .write()
+            .map_err(|_| SlashingProtectionError::Poisoned)?;
+
+        writeln!(file, "{} {}", pubkey_key(pubkey), slot)
+            .and_then(|_| file.flush())
+            .and_then(|_| file.sync_data())
+            .map_err(|e| SlashingProtectionError::StorageFailure(format!("{:?}", e)))?;
+
+        self.records.record_block_proposal(pubkey, slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::Keypair;
+
+    #[test]
+    fn in_memory() {
+        let store = InMemorySlashingProtection::default();
+        let pubkey = Keypair::random().pk;
+        let other_pubkey = Keypair::random().pk;
+
+        assert!(!store.is_slashable_block(&pubkey, Slot::new(10)));
+
+        store.record_block_proposal(&pubkey, Slot::new(10)).unwrap();
+
+        assert!(store.is_slashable_block(&pubkey, Slot::new(9)));
+        assert!(store.is_slashable_block(&pubkey, Slot::new(10)));
+        assert!(!store.is_slashable_block(&pubkey, Slot::new(11)));
+        // Records are per-validator.
+        assert!(!store.is_slashable_block(&other_pubkey, Slot::new(10)));
+    }
+
+    #[test]
+    fn file_records_survive_reopening() {
+        let path = std::env::temp_dir().join(format!(
+            "block_producer_slashing_protection_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let pubkey = Keypair::random().pk;
+
+        {
+            let store = FileSlashingProtection::open(&path).unwrap();
+            assert!(!store.is_slashable_block(&pubkey, Slot::new(10)));
+            store.record_block_proposal(&pubkey, Slot::new(10)).unwrap();
+            store.record_block_proposal(&pubkey, Slot::new(5)).unwrap();
+            assert!(store.is_slashable_block(&pubkey, Slot::new(10)));
+        }
+
+        let store = FileSlashingProtection::open(&path).unwrap();
+        assert!(store.is_slashable_block(&pubkey, Slot::new(10)));
+        assert!(!store.is_slashable_block(&pubkey, Slot::new(11)));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
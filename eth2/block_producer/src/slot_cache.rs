@@ -0,0 +1,145 @@
+use std::collections::{HashMap, VecDeque};
+use types::Slot;
+
+/// A bounded cache of values keyed by `Slot`.
+///
+/// Once `capacity` is reached, inserting a new slot evicts the least-recently-used entry.
+pub struct SlotCache<V> {
+    capacity: usize,
+    map: HashMap<Slot, V>,
+    /// Slots ordered from least-recently-used (front) to most-recently-used (back).
+    order: VecDeque<Slot>,
+}
+
+impl<V> SlotCache<V> {
+    /// Returns a new, empty cache which holds at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts `value` at `slot`, returning the previous value at `slot` (if any).
+    ///
+    /// Evicts the least-recently-used entry if the cache is full.
+    pub fn insert(&mut self, slot: Slot, value: V) -> Option<V> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        let previous = self.map.insert(slot, value);
+
+        if previous.is_some() {
+            self.touch(slot);
+        } else {
+            self.order.push_back(slot);
+            if self.order.len() > self.capacity {
+                if let Some(evicted) = self.order.pop_front() {
+                    self.map.remove(&evicted);
+                }
+            }
+        }
+
+        previous
+    }
+
+    /// Returns the value at `slot` (if any), marking it as the most-recently-used entry.
+    pub fn get(&mut self, slot: Slot) -> Option<&V> {
+        if self.map.contains_key(&slot) {
+            self.touch(slot);
+        }
+        self.map.get(&slot)
+    }
+
+    /// Returns `true` if there is a value at `slot`. Does not affect the eviction order.
+    pub fn contains(&self, slot: Slot) -> bool {
+        self.map.contains_key(&slot)
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the maximum number of entries the cache will hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Moves `slot` to the most-recently-used position.
+    fn touch(&mut self, slot: Slot) {
+        if let Some(i) = self.order.iter().position(|s| *s == slot) {
+            self.order.remove(i);
+        }
+        self.order.push_back(slot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_get() {
+        let mut cache = SlotCache::new(4);
+        assert!(cache.is_empty());
+
+        assert_eq!(cache.insert(Slot::new(1), "a"), None);
+        assert_eq!(cache.insert(Slot::new(2), "b"), None);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(Slot::new(1)), Some(&"a"));
+        assert_eq!(cache.get(Slot::new(2)), Some(&"b"));
+        assert_eq!(cache.get(Slot::new(3)), None);
+
+        // Replacing a value returns the previous value.
+        assert_eq!(cache.insert(Slot::new(1), "c"), Some("a"));
+        assert_eq!(cache.get(Slot::new(1)), Some(&"c"));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn evicts_oldest_beyond_capacity() {
+        let mut cache = SlotCache::new(2);
+
+        cache.insert(Slot::new(1), 1);
+        cache.insert(Slot::new(2), 2);
+        cache.insert(Slot::new(3), 3);
+
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(Slot::new(1)));
+        assert!(cache.contains(Slot::new(2)));
+        assert!(cache.contains(Slot::new(3)));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = SlotCache::new(2);
+
+        cache.insert(Slot::new(1), 1);
+        cache.insert(Slot::new(2), 2);
+        // Reading slot 1 makes slot 2 the least-recently-used.
+        assert_eq!(cache.get(Slot::new(1)), Some(&1));
+        cache.insert(Slot::new(3), 3);
+
+        assert!(cache.contains(Slot::new(1)));
+        assert!(!cache.contains(Slot::new(2)));
+        assert!(cache.contains(Slot::new(3)));
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut cache = SlotCache::new(0);
+
+        assert_eq!(cache.insert(Slot::new(1), 1), None);
+        assert_eq!(cache.get(Slot::new(1)), None);
+        assert!(cache.is_empty());
+    }
+}
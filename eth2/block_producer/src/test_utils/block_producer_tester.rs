@@ -0,0 +1,246 @@
+use super::{null_logger, EpochMap, LocalSigner, SimulatedBeaconNode};
+use crate::traits::{
+    BeaconNodeError, ProduceOutcome, PublishOutcome, SignerError, ValidatorStatus,
+};
+use crate::{BlockProducer, InMemorySlashingProtection};
+use slog::Logger;
+use slot_clock::TestingSlotClock;
+use std::sync::Arc;
+use types::test_utils::{SeedableRng, TestRandom, XorShiftRng};
+use types::{BeaconBlock, ChainSpec, Keypair, Slot};
+
+/// A `BlockProducer` wired to the test-only implementations of its dependencies.
+pub type TestBlockProducer = BlockProducer<
+    TestingSlotClock,
+    SimulatedBeaconNode,
+    EpochMap,
+    LocalSigner,
+    InMemorySlashingProtection,
+>;
+
+/// Handles to the test-only dependencies of a `TestBlockProducer`, for configuring and inspecting
+/// them after it is built.
+pub struct BlockProducerMocks {
+    pub spec: Arc<ChainSpec>,
+    pub keypair: Keypair,
+    /// The first of the `produce_slots`.
+    pub produce_slot: Slot,
+    pub produce_slots: Vec<Slot>,
+    pub slot_clock: Arc<TestingSlotClock>,
+    pub beacon_node: Arc<SimulatedBeaconNode>,
+    pub signer: Arc<LocalSigner>,
+    pub epoch_map: Arc<EpochMap>,
+    pub slashing_protection: Arc<InMemorySlashingProtection>,
+}
+
+/// Builds a `TestBlockProducer` for an active validator which is required to produce a block at
+/// a single slot (slot 100, by default).
+///
+/// By default the Beacon Node produces a random block for each production slot (in order) and
+/// accepts every published block, and the signer signs every message with a key other than the
+/// validator's.
+pub struct BlockProducerTester {
+    spec: ChainSpec,
+    produce_slots: Vec<Slot>,
+    keypair: Option<Keypair>,
+    signer_error: Option<SignerError>,
+    beacon_node_failure: Option<BeaconNodeError>,
+    log: Option<Logger>,
+}
+
+impl Default for BlockProducerTester {
+    fn default() -> Self {
+        Self {
+            spec: ChainSpec::foundation(),
+            produce_slots: vec![Slot::new(100)],
+            keypair: None,
+            signer_error: None,
+            beacon_node_failure: None,
+            log: None,
+        }
+    }
+}
+
+impl BlockProducerTester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the given `spec`, instead of `ChainSpec::foundation()`.
+    pub fn with_spec(mut self, spec: ChainSpec) -> Self {
+        self.spec = spec;
+        self
+    }
+
+    /// Require the validator to produce a block at `slot`, instead of slot 100.
+    pub fn with_produce_slot(mut self, slot: Slot) -> Self {
+        self.produce_slots = vec![slot];
+        self
+    }
+
+    /// Require the validator to produce a block at each of the given `slots`, instead of slot 100.
+    ///
+    /// The slots must be ascending, with at most one slot per epoch.
+    pub fn with_duties(mut self, slots: Vec<Slot>) -> Self {
+        assert!(
+            !slots.is_empty(),
+            "At least one production slot is required."
+        );
+        self.produce_slots = slots;
+        self
+    }
+
+    /// Use `keypair` for both the validator and its signer, so that the validator's signatures
+    /// verify against its `pubkey`.
+    pub fn with_keypair(mut self, keypair: Keypair) -> Self {
+        self.keypair = Some(keypair);
+        self
+    }
+
+    /// Have the signer fail all signing requests with `error`.
+    pub fn with_signer_error(mut self, error: SignerError) -> Self {
+        self.signer_error = Some(error);
+        self
+    }
+
+    /// Have the Beacon Node fail all block production requests with `error`.
+    pub fn with_beacon_node_failure(mut self, error: BeaconNodeError) -> Self {
+        self.beacon_node_failure = Some(error);
+        self
+    }
+
+    /// Log to `log`, instead of discarding all log records.
+    pub fn with_logger(mut self, log: Logger) -> Self {
+        self.log = Some(log);
+        self
+    }
+
+    /// Returns the `TestBlockProducer` and handles to its dependencies. The slot clock is set to
+    /// slot 0.
+    pub fn build(self) -> (TestBlockProducer, BlockProducerMocks) {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+
+        let spec = Arc::new(self.spec);
+        let (keypair, signer) = match self.keypair {
+            Some(keypair) => (keypair.clone(), Arc::new(LocalSigner::new(keypair))),
+            None => (
+                Keypair::random(),
+                Arc::new(LocalSigner::new(Keypair::random())),
+            ),
+        };
+        let slot_clock = Arc::new(TestingSlotClock::new(0));
+        let beacon_node = Arc::new(SimulatedBeaconNode::default());
+        let slashing_protection = Arc::new(InMemorySlashingProtection::default());
+
+        let mut epoch_map = EpochMap::new(spec.epoch_length);
+        for slot in &self.produce_slots {
+            epoch_map.map.insert(slot.epoch(spec.epoch_length), *slot);
+        }
+        let epoch_map = Arc::new(epoch_map);
+
+        signer.set_next_error(self.signer_error);
+
+        match self.beacon_node_failure {
+            Some(error) => beacon_node.set_next_produce_result(Err(error)),
+            None => {
+                for (i, slot) in self.produce_slots.iter().enumerate() {
+                    let mut block = BeaconBlock::random_for_test(&mut rng);
+                    block.slot = *slot;
+                    let result = Ok(ProduceOutcome::Block(block));
+                    if i == 0 {
+                        beacon_node.set_next_produce_result(result);
+                    } else {
+                        beacon_node.push_produce_result(result);
+                    }
+                }
+            }
+        }
+        beacon_node.set_next_publish_result(Ok(PublishOutcome::ValidBlock));
+        beacon_node.set_next_validator_status_result(Ok(ValidatorStatus::Active));
+
+        let block_producer = BlockProducer::new(
+            spec.clone(),
+            keypair.pk.clone(),
+            epoch_map.clone(),
+            slot_clock.clone(),
+            beacon_node.clone(),
+            signer.clone(),
+            slashing_protection.clone(),
+            self.log.unwrap_or_else(null_logger),
+        );
+
+        let mocks = BlockProducerMocks {
+            spec,
+            keypair,
+            produce_slot: self.produce_slots[0],
+            produce_slots: self.produce_slots,
+            slot_clock,
+            beacon_node,
+            signer,
+            epoch_map,
+            slashing_protection,
+        };
+
+        (block_producer, mocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, PollOutcome};
+
+    #[test]
+    fn signer_error() {
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_produce_slot(Slot::new(10))
+            .with_signer_error(SignerError::Locked)
+            .build();
+
+        mocks.slot_clock.set_slot(10);
+        assert_eq!(
+            block_producer.poll(),
+            Ok(PollOutcome::SignerLocked(Slot::new(10)))
+        );
+    }
+
+    #[test]
+    fn duties() {
+        let produce_slots = vec![Slot::new(10), Slot::new(100)];
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_keypair(Keypair::random())
+            .with_duties(produce_slots.clone())
+            .build();
+        assert_eq!(mocks.produce_slot, produce_slots[0]);
+
+        for slot in &produce_slots {
+            mocks.slot_clock.set_slot(slot.as_u64());
+            assert!(block_producer.poll().is_ok());
+
+            let block = mocks
+                .beacon_node
+                .publish_input
+                .read()
+                .unwrap()
+                .clone()
+                .unwrap();
+            assert_eq!(block.slot, *slot);
+            let signing_root = block_producer.proposal_signing_root(&block);
+            assert!(block.signature.verify(&signing_root[..], &mocks.keypair.pk));
+        }
+    }
+
+    #[test]
+    fn beacon_node_failure() {
+        let (mut block_producer, mocks) = BlockProducerTester::new()
+            .with_beacon_node_failure(BeaconNodeError::DecodeFailure)
+            .build();
+
+        mocks.slot_clock.set_slot(mocks.produce_slot.as_u64());
+        assert_eq!(
+            block_producer.poll(),
+            Err(Error::BeaconNodeError(BeaconNodeError::DecodeFailure))
+        );
+        assert!(mocks.beacon_node.publish_input.read().unwrap().is_none());
+    }
+}
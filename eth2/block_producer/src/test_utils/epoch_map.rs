@@ -5,6 +5,9 @@ use types::{Epoch, Slot};
 pub struct EpochMap {
     epoch_length: u64,
     pub map: HashMap<Epoch, Slot>,
+    /// The attestation slot and shard of each epoch.
+    pub attestation_map: HashMap<Epoch, (Slot, u64)>,
+    pub validator_index: Option<u64>,
 }
 
 impl EpochMap {
@@ -12,6 +15,8 @@ impl EpochMap {
         Self {
             epoch_length,
             map: HashMap::new(),
+            attestation_map: HashMap::new(),
+            validator_index: None,
         }
     }
 }
@@ -25,4 +30,57 @@ impl DutiesReader for EpochMap {
             _ => Err(DutiesReaderError::UnknownEpoch),
         }
     }
+
+    /// An `EpochMap` only ever stores the duties of a single validator.
+    fn managed_validator_count(&self) -> Result<usize, DutiesReaderError> {
+        Ok(1)
+    }
+
+    fn duties_epoch(&self) -> Result<Option<Epoch>, DutiesReaderError> {
+        Ok(self.map.keys().max().cloned())
+    }
+
+    fn attestation_shard(&self, slot: Slot) -> Result<Option<u64>, DutiesReaderError> {
+        let epoch = slot.epoch(self.epoch_length);
+        match self.attestation_map.get(&epoch) {
+            Some((attest_slot, attest_shard)) if *attest_slot == slot => Ok(Some(*attest_shard)),
+            Some(_) => Ok(None),
+            None => Err(DutiesReaderError::UnknownEpoch),
+        }
+    }
+
+    fn validator_index(&self) -> Option<u64> {
+        self.validator_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn managed_validator_count() {
+        let epoch_length = 8;
+        let mut epoch_map = EpochMap::new(epoch_length);
+        for epoch in 0..4 {
+            let epoch = Epoch::new(epoch);
+            epoch_map.map.insert(epoch, epoch.start_slot(epoch_length));
+        }
+
+        assert_eq!(epoch_map.managed_validator_count(), Ok(1));
+    }
+
+    #[test]
+    fn duties_epoch() {
+        let epoch_length = 8;
+        let mut epoch_map = EpochMap::new(epoch_length);
+        assert_eq!(epoch_map.duties_epoch(), Ok(None));
+
+        for epoch in &[3, 1, 2] {
+            let epoch = Epoch::new(*epoch);
+            epoch_map.map.insert(epoch, epoch.start_slot(epoch_length));
+        }
+
+        assert_eq!(epoch_map.duties_epoch(), Ok(Some(Epoch::new(3))));
+    }
 }
@@ -1,11 +1,14 @@
-use crate::traits::Signer;
+use crate::traits::{Signer, SignerError};
 use std::sync::RwLock;
-use types::{Keypair, Signature};
+use types::{Keypair, PublicKey, Signature};
 
 /// A test-only struct used to simulate a Beacon Node.
 pub struct LocalSigner {
     keypair: Keypair,
     should_sign: RwLock<bool>,
+    next_error: RwLock<Option<SignerError>>,
+    signed_for: RwLock<Vec<PublicKey>>,
+    batch_sizes: RwLock<Vec<usize>>,
 }
 
 impl LocalSigner {
@@ -14,6 +17,9 @@ impl LocalSigner {
         Self {
             keypair,
             should_sign: RwLock::new(true),
+            next_error: RwLock::new(None),
+            signed_for: RwLock::new(vec![]),
+            batch_sizes: RwLock::new(vec![]),
         }
     }
 
@@ -22,14 +28,71 @@ impl LocalSigner {
     pub fn enable_signing(&self, enabled: bool) {
         *self.should_sign.write().unwrap() = enabled;
     }
+
+    /// If set to `Some(error)`, all signing requests will fail with `error`. Otherwise, all
+    /// messages will be signed.
+    pub fn set_next_error(&self, error: Option<SignerError>) {
+        *self.next_error.write().unwrap() = error;
+    }
+
+    /// Returns the `pubkey` passed to each pubkey-aware signing request, in order.
+    pub fn signed_for(&self) -> Vec<PublicKey> {
+        self.signed_for.read().unwrap().clone()
+    }
+
+    /// Returns the number of messages in each call to `sign_block_proposal_batch`, in order.
+    pub fn batch_sizes(&self) -> Vec<usize> {
+        self.batch_sizes.read().unwrap().clone()
+    }
+
+    /// Sign some message, unless an error has been set.
+    fn bls_sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        match *self.next_error.read().unwrap() {
+            Some(ref e) => Err(e.clone()),
+            None => Ok(Signature::new(message, &self.keypair.sk)),
+        }
+    }
 }
 
 impl Signer for LocalSigner {
-    fn sign_block_proposal(&self, message: &[u8]) -> Option<Signature> {
-        Some(Signature::new(message, &self.keypair.sk))
+    fn sign_block_proposal(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.bls_sign(message)
+    }
+
+    fn sign_randao_reveal(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.bls_sign(message)
+    }
+
+    fn sign_attestation_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.bls_sign(message)
+    }
+
+    fn sign_block_proposal_for(
+        &self,
+        pubkey: &PublicKey,
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        self.signed_for.write().unwrap().push(pubkey.clone());
+        self.sign_block_proposal(message)
+    }
+
+    fn sign_randao_reveal_for(
+        &self,
+        pubkey: &PublicKey,
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        self.signed_for.write().unwrap().push(pubkey.clone());
+        self.sign_randao_reveal(message)
     }
 
-    fn sign_randao_reveal(&self, message: &[u8]) -> Option<Signature> {
-        Some(Signature::new(message, &self.keypair.sk))
+    fn sign_block_proposal_batch(
+        &self,
+        messages: &[(&PublicKey, &[u8])],
+    ) -> Vec<Result<Signature, SignerError>> {
+        self.batch_sizes.write().unwrap().push(messages.len());
+        messages
+            .iter()
+            .map(|(pubkey, message)| self.sign_block_proposal_for(pubkey, message))
+            .collect()
     }
 }
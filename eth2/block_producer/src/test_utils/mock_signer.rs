@@ -0,0 +1,102 @@
+use crate::traits::{Signer, SignerError};
+use std::sync::RwLock;
+use types::test_utils::{SeedableRng, TestRandom, XorShiftRng};
+use types::{Keypair, PublicKey, Signature};
+
+/// A test-only `Signer` which signs with a fixed (seeded) key, so that a given message always
+/// yields the same signature, regardless of the instance or test run.
+pub struct MockSigner {
+    keypair: Keypair,
+    should_sign: RwLock<bool>,
+}
+
+impl Default for MockSigner {
+    fn default() -> Self {
+        let sk = TestRandom::random_for_test(&mut XorShiftRng::from_seed([42; 16]));
+
+        Self {
+            keypair: Keypair {
+                pk: PublicKey::from_secret_key(&sk),
+                sk,
+            },
+            should_sign: RwLock::new(true),
+        }
+    }
+}
+
+impl MockSigner {
+    /// If set to `false`, all signing requests will be rejected. Otherwise, all messages will be
+    /// signed.
+    pub fn enable_signing(&self, enabled: bool) {
+        *self.should_sign.write().unwrap() = enabled;
+    }
+
+    /// Returns the public key of the fixed key.
+    pub fn pubkey(&self) -> &PublicKey {
+        &self.keypair.pk
+    }
+
+    /// Returns the signature of `message`, or `None` if signing is disabled.
+    pub fn mock_signature(&self, message: &[u8]) -> Option<Signature> {
+        if *self.should_sign.read().unwrap() {
+            Some(Signature::new(message, &self.keypair.sk))
+        } else {
+            None
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.mock_signature(message)
+            .ok_or_else(|| SignerError::Backend("Signing is disabled.".to_string()))
+    }
+}
+
+impl Signer for MockSigner {
+    fn sign_block_proposal(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign(message)
+    }
+
+    fn sign_randao_reveal(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign(message)
+    }
+
+    fn sign_attestation_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic_signatures() {
+        let signer = MockSigner::default();
+        let message = [42; 32];
+
+        let signature = signer.sign_block_proposal(&message).unwrap();
+        assert_eq!(signer.sign_block_proposal(&message), Ok(signature.clone()));
+        assert_eq!(
+            MockSigner::default().sign_randao_reveal(&message),
+            Ok(signature.clone())
+        );
+        assert!(signature.verify(&message, signer.pubkey()));
+
+        assert_ne!(signer.sign_block_proposal(&[43; 32]), Ok(signature));
+    }
+
+    #[test]
+    fn disabled() {
+        let signer = MockSigner::default();
+        signer.enable_signing(false);
+
+        assert_eq!(signer.mock_signature(&[42; 32]), None);
+        assert_eq!(
+            signer.sign_block_proposal(&[42; 32]),
+            Err(SignerError::Backend("Signing is disabled.".to_string()))
+        );
+
+        signer.enable_signing(true);
+        assert!(signer.mock_signature(&[42; 32]).is_some());
+    }
+}
@@ -1,7 +1,20 @@
+mod block_producer_tester;
 mod epoch_map;
 mod local_signer;
+mod mock_signer;
 mod simulated_beacon_node;
+mod simulated_block_relay;
 
+pub use self::block_producer_tester::{BlockProducerMocks, BlockProducerTester, TestBlockProducer};
 pub use self::epoch_map::EpochMap;
 pub use self::local_signer::LocalSigner;
+pub use self::mock_signer::MockSigner;
 pub use self::simulated_beacon_node::SimulatedBeaconNode;
+pub use self::simulated_block_relay::SimulatedBlockRelay;
+
+use slog::{o, Discard, Logger};
+
+/// Returns a `Logger` which discards all records.
+pub fn null_logger() -> Logger {
+    Logger::root(Discard, o!())
+}
@@ -1,65 +1,297 @@
-use crate::traits::{BeaconNode, BeaconNodeError, PublishOutcome};
+use crate::traits::{
+    AttestationPublishOutcome, BeaconNode, BeaconNodeError, NodeHealth, ProduceOutcome,
+    PublishOutcome, ValidatorStatus,
+};
+use std::collections::VecDeque;
 use std::sync::RwLock;
-use types::{BeaconBlock, PublicKey, Signature, Slot};
+use std::thread;
+use std::time::Duration;
+use types::{AttestationData, BeaconBlock, FreeAttestation, Hash256, PublicKey, Signature, Slot};
 
 type NonceResult = Result<u64, BeaconNodeError>;
-type ProduceResult = Result<Option<BeaconBlock>, BeaconNodeError>;
+type PeerCountResult = Result<u32, BeaconNodeError>;
+type EpochLengthResult = Result<Option<u64>, BeaconNodeError>;
+type ValidatorStatusResult = Result<ValidatorStatus, BeaconNodeError>;
+type HealthResult = Result<NodeHealth, BeaconNodeError>;
+type ProduceResult = Result<ProduceOutcome, BeaconNodeError>;
 type PublishResult = Result<PublishOutcome, BeaconNodeError>;
+type ProduceAttestationResult = Result<Option<AttestationData>, BeaconNodeError>;
+type PublishAttestationResult = Result<AttestationPublishOutcome, BeaconNodeError>;
 
 /// A test-only struct used to simulate a Beacon Node.
+///
+/// The results of `proposer_nonce`, `produce_beacon_block` and `publish_beacon_block` are queued
+/// and returned in order, with the last result repeating once the queue is exhausted.
 #[derive(Default)]
 pub struct SimulatedBeaconNode {
     pub nonce_input: RwLock<Option<PublicKey>>,
-    pub nonce_result: RwLock<Option<NonceResult>>,
+    pub nonce_result: RwLock<VecDeque<NonceResult>>,
 
-    pub produce_input: RwLock<Option<(Slot, Signature)>>,
-    pub produce_result: RwLock<Option<ProduceResult>>,
+    pub peer_count_result: RwLock<Option<PeerCountResult>>,
+
+    pub epoch_length_result: RwLock<Option<EpochLengthResult>>,
+
+    pub validator_status_input: RwLock<Option<PublicKey>>,
+    pub validator_status_result: RwLock<Option<ValidatorStatusResult>>,
+
+    pub health_result: RwLock<Option<HealthResult>>,
+
+    pub produce_input: RwLock<Option<(Slot, Signature, [u8; 32])>>,
+    pub produce_result: RwLock<VecDeque<ProduceResult>>,
+    pub produce_failures: RwLock<VecDeque<BeaconNodeError>>,
+    pub randao_reveal_override: RwLock<Option<Signature>>,
+
+    pub produce_minimal_input: RwLock<Option<(Slot, Signature, [u8; 32])>>,
+    pub produce_minimal_result: RwLock<Option<ProduceResult>>,
 
     pub publish_input: RwLock<Option<BeaconBlock>>,
-    pub publish_result: RwLock<Option<PublishResult>>,
+    pub publish_result: RwLock<VecDeque<PublishResult>>,
+
+    pub produce_attestation_input: RwLock<Option<(Slot, u64)>>,
+    pub produce_attestation_result: RwLock<Option<ProduceAttestationResult>>,
+
+    pub publish_attestation_input: RwLock<Option<FreeAttestation>>,
+    pub publish_attestation_result: RwLock<Option<PublishAttestationResult>>,
+
+    pub request_delay: RwLock<Duration>,
 }
 
 impl SimulatedBeaconNode {
-    /// Set the result to be returned when `produce_beacon_block` is called.
+    /// Set the result to be returned when `proposer_nonce` is called, replacing any queued
+    /// results.
     pub fn set_next_nonce_result(&self, result: NonceResult) {
-        *self.nonce_result.write().unwrap() = Some(result);
+        *self.nonce_result.write().unwrap() = VecDeque::from(vec![result]);
+    }
+
+    /// Queue a result to be returned when `proposer_nonce` is called, after those already
+    /// queued.
+    pub fn push_nonce_result(&self, result: NonceResult) {
+        self.nonce_result.write().unwrap().push_back(result);
+    }
+
+    /// Set the result to be returned when `peer_count` is called.
+    pub fn set_next_peer_count_result(&self, result: PeerCountResult) {
+        *self.peer_count_result.write().unwrap() = Some(result);
+    }
+
+    /// Set the result to be returned when `epoch_length` is called.
+    pub fn set_next_epoch_length_result(&self, result: EpochLengthResult) {
+        *self.epoch_length_result.write().unwrap() = Some(result);
+    }
+
+    /// Set the result to be returned when `validator_status` is called.
+    pub fn set_next_validator_status_result(&self, result: ValidatorStatusResult) {
+        *self.validator_status_result.write().unwrap() = Some(result);
+    }
+
+    /// Delay each block production and publication request by `delay`, as a slow Beacon Node
+    /// would.
+    pub fn set_request_delay(&self, delay: Duration) {
+        *self.request_delay.write().unwrap() = delay;
+    }
+
+    /// Set the result to be returned when `health` is called.
+    pub fn set_next_health_result(&self, result: HealthResult) {
+        *self.health_result.write().unwrap() = Some(result);
     }
 
-    /// Set the result to be returned when `produce_beacon_block` is called.
+    /// Set the result to be returned when `produce_beacon_block` is called, replacing any queued
+    /// results.
     pub fn set_next_produce_result(&self, result: ProduceResult) {
-        *self.produce_result.write().unwrap() = Some(result);
+        *self.produce_result.write().unwrap() = VecDeque::from(vec![result]);
+    }
+
+    /// Queue a result to be returned when `produce_beacon_block` is called, after those already
+    /// queued.
+    pub fn push_produce_result(&self, result: ProduceResult) {
+        self.produce_result.write().unwrap().push_back(result);
+    }
+
+    /// Queue an error to be returned when `produce_beacon_block` is called. Queued errors are
+    /// returned (in order) before the result specified by `set_next_produce_result`.
+    pub fn push_produce_failure(&self, error: BeaconNodeError) {
+        self.produce_failures.write().unwrap().push_back(error);
+    }
+
+    /// If set to `Some(randao_reveal)`, produced blocks will include `randao_reveal` instead of the
+    /// supplied RANDAO reveal, as if they were produced for another proposer.
+    pub fn set_randao_reveal_override(&self, randao_reveal: Option<Signature>) {
+        *self.randao_reveal_override.write().unwrap() = randao_reveal;
     }
 
-    /// Set the result to be returned when `publish_beacon_block` is called.
+    /// Set the result to be returned when `produce_minimal_block` is called.
+    pub fn set_next_produce_minimal_result(&self, result: ProduceResult) {
+        *self.produce_minimal_result.write().unwrap() = Some(result);
+    }
+
+    /// Set the result to be returned when `publish_beacon_block` is called, replacing any queued
+    /// results.
     pub fn set_next_publish_result(&self, result: PublishResult) {
-        *self.publish_result.write().unwrap() = Some(result);
+        *self.publish_result.write().unwrap() = VecDeque::from(vec![result]);
+    }
+
+    /// Queue a result to be returned when `publish_beacon_block` is called, after those already
+    /// queued.
+    pub fn push_publish_result(&self, result: PublishResult) {
+        self.publish_result.write().unwrap().push_back(result);
+    }
+
+    /// Set the result to be returned when `produce_attestation` is called.
+    pub fn set_next_produce_attestation_result(&self, result: ProduceAttestationResult) {
+        *self.produce_attestation_result.write().unwrap() = Some(result);
+    }
+
+    /// Set the result to be returned when `publish_attestation` is called.
+    pub fn set_next_publish_attestation_result(&self, result: PublishAttestationResult) {
+        *self.publish_attestation_result.write().unwrap() = Some(result);
     }
 }
 
 impl BeaconNode for SimulatedBeaconNode {
+    /// Returns the next result queued by `set_next_nonce_result` or `push_nonce_result`.
     fn proposer_nonce(&self, pubkey: &PublicKey) -> NonceResult {
         *self.nonce_input.write().unwrap() = Some(pubkey.clone());
-        match *self.nonce_result.read().unwrap() {
+        next_result(&self.nonce_result, "nonce_result")
+    }
+
+    /// Returns the value specified by the `set_next_peer_count_result`.
+    fn peer_count(&self) -> PeerCountResult {
+        match *self.peer_count_result.read().unwrap() {
             Some(ref r) => r.clone(),
-            None => panic!("SimulatedBeaconNode: nonce_result == None"),
+            None => panic!("SimulatedBeaconNode: peer_count_result == None"),
         }
     }
 
-    /// Returns the value specified by the `set_next_produce_result`.
-    fn produce_beacon_block(&self, slot: Slot, randao_reveal: &Signature) -> ProduceResult {
-        *self.produce_input.write().unwrap() = Some((slot, randao_reveal.clone()));
-        match *self.produce_result.read().unwrap() {
+    /// Returns the value specified by the `set_next_epoch_length_result`.
+    ///
+    /// Returns `Ok(None)` if no result has been specified.
+    fn epoch_length(&self) -> EpochLengthResult {
+        match *self.epoch_length_result.read().unwrap() {
             Some(ref r) => r.clone(),
-            None => panic!("SimulatedBeaconNode: produce_result == None"),
+            None => Ok(None),
         }
     }
 
-    /// Returns the value specified by the `set_next_publish_result`.
+    /// Returns the value specified by the `set_next_validator_status_result`.
+    fn validator_status(&self, pubkey: &PublicKey) -> ValidatorStatusResult {
+        *self.validator_status_input.write().unwrap() = Some(pubkey.clone());
+        match *self.validator_status_result.read().unwrap() {
+            Some(ref r) => r.clone(),
+            None => panic!("SimulatedBeaconNode: validator_status_result == None"),
+        }
+    }
+
+    /// Returns the value specified by the `set_next_health_result`.
+    fn health(&self) -> HealthResult {
+        match *self.health_result.read().unwrap() {
+            Some(ref r) => r.clone(),
+            None => panic!("SimulatedBeaconNode: health_result == None"),
+        }
+    }
+
+    /// Returns the next error queued by `push_produce_failure`, or else the next result queued by
+    /// `set_next_produce_result` or `push_produce_result` with the `randao_reveal` and `graffiti`
+    /// inserted into any block.
+    fn produce_beacon_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> ProduceResult {
+        *self.produce_input.write().unwrap() = Some((slot, randao_reveal.clone(), *graffiti));
+        self.delay();
+        if let Some(error) = self.produce_failures.write().unwrap().pop_front() {
+            return Err(error);
+        }
+        let result = next_result(&self.produce_result, "produce_result");
+        self.as_produced(result, randao_reveal, graffiti)
+    }
+
+    /// Returns the value specified by the `set_next_produce_minimal_result`, with the
+    /// `randao_reveal` and `graffiti` inserted into any block.
+    fn produce_minimal_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> ProduceResult {
+        *self.produce_minimal_input.write().unwrap() =
+            Some((slot, randao_reveal.clone(), *graffiti));
+        self.delay();
+        match *self.produce_minimal_result.read().unwrap() {
+            Some(ref r) => self.as_produced(r.clone(), randao_reveal, graffiti),
+            None => panic!("SimulatedBeaconNode: produce_minimal_result == None"),
+        }
+    }
+
+    /// Returns the next result queued by `set_next_publish_result` or `push_publish_result`.
     fn publish_beacon_block(&self, block: BeaconBlock) -> PublishResult {
         *self.publish_input.write().unwrap() = Some(block);
-        match *self.publish_result.read().unwrap() {
+        self.delay();
+        next_result(&self.publish_result, "publish_result")
+    }
+
+    /// Returns the value specified by the `set_next_produce_attestation_result`.
+    fn produce_attestation(&self, slot: Slot, shard: u64) -> ProduceAttestationResult {
+        *self.produce_attestation_input.write().unwrap() = Some((slot, shard));
+        match *self.produce_attestation_result.read().unwrap() {
+            Some(ref r) => r.clone(),
+            None => panic!("SimulatedBeaconNode: produce_attestation_result == None"),
+        }
+    }
+
+    /// Returns the value specified by the `set_next_publish_attestation_result`.
+    fn publish_attestation(&self, free_attestation: FreeAttestation) -> PublishAttestationResult {
+        *self.publish_attestation_input.write().unwrap() = Some(free_attestation);
+        match *self.publish_attestation_result.read().unwrap() {
             Some(ref r) => r.clone(),
-            None => panic!("SimulatedBeaconNode: publish_result == None"),
+            None => panic!("SimulatedBeaconNode: publish_attestation_result == None"),
+        }
+    }
+}
+
+/// Removes and returns the result at the front of the `queue`, unless it is the last result, in
+/// which case it is cloned so it will be returned again.
+fn next_result<T: Clone>(queue: &RwLock<VecDeque<T>>, name: &str) -> T {
+    let mut queue = queue.write().unwrap();
+    if queue.len() > 1 {
+        queue.pop_front().unwrap()
+    } else {
+        match queue.front() {
+            Some(r) => r.clone(),
+            None => panic!("SimulatedBeaconNode: {} is empty", name),
         }
     }
 }
+
+impl SimulatedBeaconNode {
+    /// Sleeps for the `request_delay`.
+    fn delay(&self) {
+        thread::sleep(*self.request_delay.read().unwrap());
+    }
+
+    /// Sets the RANDAO reveal (unless overridden) and graffiti of the block in `result` (if any),
+    /// as a Beacon Node would.
+    fn as_produced(
+        &self,
+        result: ProduceResult,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> ProduceResult {
+        let randao_reveal = self
+            .randao_reveal_override
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap_or_else(|| randao_reveal.clone());
+
+        result.map(|outcome| match outcome {
+            ProduceOutcome::Block(mut block) => {
+                block.randao_reveal = randao_reveal;
+                block.body.graffiti = Hash256::from(&graffiti[..]);
+                ProduceOutcome::Block(block)
+            }
+            outcome => outcome,
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::traits::{BlockRelay, BlockRelayError};
+use std::sync::RwLock;
+use types::BeaconBlock;
+
+/// A test-only struct used to simulate a block relay.
+#[derive(Default)]
+pub struct SimulatedBlockRelay {
+    pub submitted: RwLock<Vec<BeaconBlock>>,
+    pub submit_error: RwLock<Option<BlockRelayError>>,
+}
+
+impl SimulatedBlockRelay {
+    /// If set to `Some(error)`, all submissions will fail with `error` (though they will still be
+    /// recorded in `submitted`).
+    pub fn set_submit_error(&self, error: Option<BlockRelayError>) {
+        *self.submit_error.write().unwrap() = error;
+    }
+}
+
+impl BlockRelay for SimulatedBlockRelay {
+    /// Records the `block` in `submitted`, returning the error set by `set_submit_error` (if any).
+    fn submit(&self, block: BeaconBlock) -> Result<(), BlockRelayError> {
+        self.submitted.write().unwrap().push(block);
+        match *self.submit_error.read().unwrap() {
+            Some(ref e) => Err(e.clone()),
+            None => Ok(()),
+        }
+    }
+}
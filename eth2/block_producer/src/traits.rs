@@ -1,15 +1,82 @@
-use types::{BeaconBlock, PublicKey, Signature, Slot};
+use types::{AttestationData, BeaconBlock, Epoch, FreeAttestation, PublicKey, Signature, Slot};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum BeaconNodeError {
     RemoteFailure(String),
+    /// The Beacon Node could not be reached, the request may succeed if retried.
+    Unavailable(String),
     DecodeFailure,
+    Timeout,
+    /// Each of several Beacon Nodes failed, with these errors (in the order they were tried).
+    AllNodesFailed(Vec<BeaconNodeError>),
+}
+
+impl BeaconNodeError {
+    /// Returns `true` if the error is transient and the request may succeed if retried.
+    ///
+    /// A `Timeout` is not considered retryable, as the slot is likely to have mostly elapsed.
+    /// `AllNodesFailed` is retryable if any of its errors are.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BeaconNodeError::Unavailable(_) => true,
+            BeaconNodeError::AllNodesFailed(errors) => errors.iter().any(|e| e.is_retryable()),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum PublishOutcome {
     ValidBlock,
     InvalidBlock(String),
+    /// The block was rejected as a conflicting block already exists at its slot, i.e., publishing
+    /// it would be slashable.
+    RejectedSlashable,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AttestationPublishOutcome {
+    ValidAttestation,
+    InvalidAttestation(String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValidatorStatus {
+    /// The validator is not known to the Beacon Node.
+    Unknown,
+    /// The validator is known, but is yet to be activated.
+    Pending,
+    /// The validator is active.
+    Active,
+    /// The validator has exited.
+    Exited,
+}
+
+/// The reason a Beacon Node gave for not producing a block.
+#[derive(Debug, PartialEq, Clone)]
+pub enum NoBlockReason {
+    /// The Beacon Node is syncing, so it does not know the head upon which to build.
+    Syncing,
+    /// The Beacon Node does not know the parent of the block.
+    MissingParent,
+    /// The Beacon Node did not state a reason.
+    Unspecified,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ProduceOutcome {
+    Block(BeaconBlock),
+    /// The Beacon Node is healthy, but was unable to produce a block at the given slot.
+    NoBlockAvailable(NoBlockReason),
+}
+
+/// The health of a Beacon Node, as reported by the node.
+#[derive(Debug, PartialEq, Clone)]
+pub struct NodeHealth {
+    /// `true` if the node is still syncing the chain, so is unlikely to produce a valid block.
+    pub is_syncing: bool,
+    /// The slot of the node's head block.
+    pub head_slot: Slot,
 }
 
 /// Defines the methods required to produce and publish blocks on a Beacon Node.
@@ -17,19 +84,62 @@ pub trait BeaconNode: Send + Sync {
     /// Requests the proposer nonce (presently named `proposer_slots`).
     fn proposer_nonce(&self, pubkey: &PublicKey) -> Result<u64, BeaconNodeError>;
 
-    /// Request that the node produces a block.
+    /// Requests the number of peers the Beacon Node is connected to.
+    fn peer_count(&self) -> Result<u32, BeaconNodeError>;
+
+    /// Requests the number of slots per epoch used by the Beacon Node.
     ///
-    /// Returns Ok(None) if the Beacon Node is unable to produce at the given slot.
+    /// Returns `Ok(None)` if the Beacon Node does not report its epoch length.
+    fn epoch_length(&self) -> Result<Option<u64>, BeaconNodeError>;
+
+    /// Requests the activation status of the validator with the given `pubkey`.
+    fn validator_status(&self, pubkey: &PublicKey) -> Result<ValidatorStatus, BeaconNodeError>;
+
+    /// Requests the sync status and head of the Beacon Node.
+    fn health(&self) -> Result<NodeHealth, BeaconNodeError>;
+
+    /// Request that the node produces a block, including the given `graffiti` in its body.
+    ///
+    /// Returns `Ok(ProduceOutcome::NoBlockAvailable(reason))` if the Beacon Node is unable to
+    /// produce at the given slot.
     fn produce_beacon_block(
         &self,
         slot: Slot,
         randao_reveal: &Signature,
-    ) -> Result<Option<BeaconBlock>, BeaconNodeError>;
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError>;
+
+    /// Request that the node produces a minimal block (e.g., one without attestations), which
+    /// should be quicker to assemble than a full block.
+    ///
+    /// Returns `Ok(ProduceOutcome::NoBlockAvailable(reason))` if the Beacon Node is unable to
+    /// produce at the given slot.
+    fn produce_minimal_block(
+        &self,
+        slot: Slot,
+        randao_reveal: &Signature,
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError>;
 
     /// Request that the node publishes a block.
     ///
     /// Returns `true` if the publish was sucessful.
     fn publish_beacon_block(&self, block: BeaconBlock) -> Result<PublishOutcome, BeaconNodeError>;
+
+    /// Request that the node produces the `AttestationData` for the given `shard` at `slot`.
+    ///
+    /// Returns `Ok(None)` if the Beacon Node is unable to produce at the given slot.
+    fn produce_attestation(
+        &self,
+        slot: Slot,
+        shard: u64,
+    ) -> Result<Option<AttestationData>, BeaconNodeError>;
+
+    /// Request that the node publishes a signed attestation.
+    fn publish_attestation(
+        &self,
+        free_attestation: FreeAttestation,
+    ) -> Result<AttestationPublishOutcome, BeaconNodeError>;
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -43,10 +153,137 @@ pub enum DutiesReaderError {
 /// Informs a validator of their duties (e.g., block production).
 pub trait DutiesReader: Send + Sync {
     fn is_block_production_slot(&self, slot: Slot) -> Result<bool, DutiesReaderError>;
+
+    /// Returns the number of validators for which this reader provides duties.
+    fn managed_validator_count(&self) -> Result<usize, DutiesReaderError>;
+
+    /// Returns the latest epoch for which duties are known, or `None` if no duties are known.
+    fn duties_epoch(&self) -> Result<Option<Epoch>, DutiesReaderError>;
+
+    /// Returns `Some(shard)` if this slot is an attestation slot. Otherwise, returns `None`.
+    ///
+    /// By default, there are no attestation duties (e.g., for a reader of proposer duties only).
+    fn attestation_shard(&self, _slot: Slot) -> Result<Option<u64>, DutiesReaderError> {
+        Ok(None)
+    }
+
+    /// Returns the index of the validator in the validator registry, if it is known.
+    ///
+    /// By default, the index is not known.
+    fn validator_index(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SlashingProtectionError {
+    StorageFailure(String),
+    Poisoned,
+}
+
+/// Records the blocks signed by validators so that slashable blocks are never signed.
+pub trait SlashingProtection: Send + Sync {
+    /// Returns `true` if signing a block at `slot` would be slashable for the validator with the
+    /// given `pubkey` (i.e., a block has already been signed at or after `slot`).
+    fn is_slashable_block(&self, pubkey: &PublicKey, slot: Slot) -> bool;
+
+    /// Records that the validator with the given `pubkey` is about to sign a block at `slot`.
+    ///
+    /// The record must be durable when this function returns `Ok`.
+    fn record_block_proposal(
+        &self,
+        pubkey: &PublicKey,
+        slot: Slot,
+    ) -> Result<(), SlashingProtectionError>;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ProcessLockError {
+    /// Another `BlockProducer` (possibly in another process) holds the lock for this validator.
+    AlreadyHeld,
+    StorageFailure(String),
+    Poisoned,
+}
+
+/// Ensures that at most one `BlockProducer` runs for each validator at any time, as running two
+/// producers with the same key is likely to be slashable.
+pub trait ProcessLock: Send + Sync {
+    /// Acquires the lock for the validator with the given `pubkey`, returning
+    /// `ProcessLockError::AlreadyHeld` if it is already held.
+    fn acquire(&self, pubkey: &PublicKey) -> Result<(), ProcessLockError>;
+
+    /// Releases the lock for the validator with the given `pubkey`.
+    fn release(&self, pubkey: &PublicKey) -> Result<(), ProcessLockError>;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SignerError {
+    /// The key is temporarily unavailable (e.g., a locked keystore), signing may be retried later.
+    Locked,
+    /// The signer does not hold a key for this validator.
+    UnknownKey,
+    /// The signing backend failed.
+    Backend(String),
 }
 
 /// Signs message using an internally-maintained private key.
 pub trait Signer {
-    fn sign_block_proposal(&self, message: &[u8]) -> Option<Signature>;
-    fn sign_randao_reveal(&self, message: &[u8]) -> Option<Signature>;
+    fn sign_block_proposal(&self, message: &[u8]) -> Result<Signature, SignerError>;
+    fn sign_randao_reveal(&self, message: &[u8]) -> Result<Signature, SignerError>;
+    fn sign_attestation_message(&self, message: &[u8]) -> Result<Signature, SignerError>;
+
+    /// As per `sign_block_proposal`, but signs using the key of the validator with `pubkey`, for
+    /// signers which hold many keys.
+    ///
+    /// By default, the `pubkey` is ignored (i.e., the signer holds a single key).
+    fn sign_block_proposal_for(
+        &self,
+        _pubkey: &PublicKey,
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        self.sign_block_proposal(message)
+    }
+
+    /// As per `sign_randao_reveal`, but signs using the key of the validator with `pubkey`, for
+    /// signers which hold many keys.
+    ///
+    /// By default, the `pubkey` is ignored (i.e., the signer holds a single key).
+    fn sign_randao_reveal_for(
+        &self,
+        _pubkey: &PublicKey,
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        self.sign_randao_reveal(message)
+    }
+
+    /// Signs each of the block proposal `messages`, using the key of the validator with the
+    /// accompanying pubkey. Returns a result for each message, in order.
+    ///
+    /// By default, each message is signed in turn. Signers which are able to sign many messages
+    /// with a single request (e.g., a remote signer) should override this.
+    ///
+    /// Each message carries a pubkey (rather than the batch being a bare `&[&[u8]]`), as a signer
+    /// may hold the keys of many validators, and each result carries its `SignerError` (rather
+    /// than an `Option`), so that a locked signer or an unknown key is reported per message.
+    fn sign_block_proposal_batch(
+        &self,
+        messages: &[(&PublicKey, &[u8])],
+    ) -> Vec<Result<Signature, SignerError>> {
+        messages
+            .iter()
+            .map(|(pubkey, message)| self.sign_block_proposal_for(pubkey, message))
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BlockRelayError {
+    RemoteFailure(String),
+}
+
+/// An external service (e.g., a "mev-boost"-style relay) to which signed blocks may be submitted,
+/// in addition to (or instead of) the Beacon Node.
+pub trait BlockRelay: Send + Sync {
+    /// Submits a signed block to the relay.
+    fn submit(&self, block: BeaconBlock) -> Result<(), BlockRelayError>;
 }
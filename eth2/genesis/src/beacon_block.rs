@@ -21,6 +21,7 @@ pub fn genesis_beacon_block(state_root: Hash256, spec: &ChainSpec) -> BeaconBloc
             custody_responses: vec![],
             deposits: vec![],
             exits: vec![],
+            graffiti: spec.zero_hash,
         },
     }
 }
@@ -22,18 +22,27 @@ impl BeaconBlock {
     }
 
     pub fn proposal_root(&self, spec: &ChainSpec) -> Hash256 {
+        Hash256::from_slice(&self.proposal_signed_data(spec).hash_tree_root()[..])
+    }
+
+    /// Returns the root which is signed to propose this block, i.e., the `proposal_root` mixed
+    /// with the signature `domain` (as per `ProposalSignedData::signing_root`).
+    pub fn proposal_signing_root(&self, spec: &ChainSpec, domain: u64) -> Hash256 {
+        self.proposal_signed_data(spec).signing_root(domain)
+    }
+
+    fn proposal_signed_data(&self, spec: &ChainSpec) -> ProposalSignedData {
         let block_without_signature_root = {
             let mut block_without_signature = self.clone();
             block_without_signature.signature = spec.empty_signature.clone();
             block_without_signature.canonical_root()
         };
 
-        let proposal = ProposalSignedData {
+        ProposalSignedData {
             slot: self.slot,
             shard: spec.beacon_chain_shard_number,
             block_root: block_without_signature_root,
-        };
-        Hash256::from_slice(&proposal.hash_tree_root()[..])
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use super::{Attestation, CasperSlashing, Deposit, Exit, ProposerSlashing};
 use crate::test_utils::TestRandom;
+use crate::Hash256;
 use rand::RngCore;
 use serde_derive::Serialize;
 use ssz::{hash, Decodable, DecodeError, Encodable, SszStream, TreeHash};
@@ -20,6 +21,8 @@ pub struct BeaconBlockBody {
     pub custody_responses: Vec<CustodyResponse>,
     pub deposits: Vec<Deposit>,
     pub exits: Vec<Exit>,
+    /// Arbitrary bytes chosen by the proposer.
+    pub graffiti: Hash256,
 }
 
 impl Encodable for BeaconBlockBody {
@@ -32,6 +35,7 @@ impl Encodable for BeaconBlockBody {
         s.append_vec(&self.custody_responses);
         s.append_vec(&self.deposits);
         s.append_vec(&self.exits);
+        s.append(&self.graffiti);
     }
 }
 
@@ -45,6 +49,7 @@ impl Decodable for BeaconBlockBody {
         let (custody_responses, i) = <_>::ssz_decode(bytes, i)?;
         let (deposits, i) = <_>::ssz_decode(bytes, i)?;
         let (exits, i) = <_>::ssz_decode(bytes, i)?;
+        let (graffiti, i) = <_>::ssz_decode(bytes, i)?;
 
         Ok((
             Self {
@@ -56,6 +61,7 @@ impl Decodable for BeaconBlockBody {
                 custody_responses,
                 deposits,
                 exits,
+                graffiti,
             },
             i,
         ))
@@ -73,6 +79,7 @@ impl TreeHash for BeaconBlockBody {
         result.append(&mut self.custody_responses.hash_tree_root());
         result.append(&mut self.deposits.hash_tree_root());
         result.append(&mut self.exits.hash_tree_root());
+        result.append(&mut self.graffiti.hash_tree_root());
         hash(&result)
     }
 }
@@ -88,6 +95,7 @@ impl<T: RngCore> TestRandom<T> for BeaconBlockBody {
             custody_responses: <_>::random_for_test(rng),
             deposits: <_>::random_for_test(rng),
             exits: <_>::random_for_test(rng),
+            graffiti: <_>::random_for_test(rng),
         }
     }
 }
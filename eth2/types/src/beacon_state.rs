@@ -19,9 +19,7 @@ use std::ops::Range;
 use vec_shuffle::shuffle;
 
 // TODO: define elsehwere.
-const DOMAIN_PROPOSAL: u64 = 2;
 const DOMAIN_EXIT: u64 = 3;
-const DOMAIN_RANDAO: u64 = 4;
 const PHASE_0_CUSTODY_BIT: bool = false;
 const DOMAIN_ATTESTATION: u64 = 1;
 
@@ -222,13 +220,7 @@ impl BeaconState {
         active_validator_count: usize,
         spec: &ChainSpec,
     ) -> u64 {
-        std::cmp::max(
-            1,
-            std::cmp::min(
-                spec.shard_count / spec.epoch_length,
-                active_validator_count as u64 / spec.epoch_length / spec.target_committee_size,
-            ),
-        )
+        spec.committees_per_slot(active_validator_count as u64)
     }
 
     fn get_previous_epoch_committee_count_per_slot(&self, spec: &ChainSpec) -> u64 {
@@ -382,12 +374,13 @@ impl BeaconState {
         let block_proposer = &self.validator_registry[block_proposer_index];
 
         if verify_block_signature {
+            let proposal_domain = get_domain(&self.fork_data, self.slot, spec.domain_proposal);
             ensure!(
                 bls_verify(
                     &block_proposer.pubkey,
-                    &block.proposal_root(spec)[..],
+                    &block.proposal_signing_root(spec, proposal_domain)[..],
                     &block.signature,
-                    get_domain(&self.fork_data, self.slot, DOMAIN_PROPOSAL)
+                    proposal_domain
                 ),
                 BlockProcessingError::BadBlockSignature
             );
@@ -396,12 +389,16 @@ impl BeaconState {
         /*
          * RANDAO
          */
+        let randao_domain = get_domain(&self.fork_data, self.slot, spec.domain_randao);
         ensure!(
             bls_verify(
                 &block_proposer.pubkey,
-                &ssz_encode(&block_proposer.proposer_slots),
+                &self
+                    .slot
+                    .epoch(spec.epoch_length)
+                    .randao_signing_root(randao_domain)[..],
                 &block.randao_reveal,
-                get_domain(&self.fork_data, self.slot, DOMAIN_RANDAO)
+                randao_domain
             ),
             BlockProcessingError::BadRandaoSignature
         );
@@ -461,7 +458,7 @@ impl BeaconState {
                     get_domain(
                         &self.fork_data,
                         proposer_slashing.proposal_data_1.slot,
-                        DOMAIN_PROPOSAL
+                        spec.domain_proposal
                     )
                 ),
                 BlockProcessingError::BadProposerSlashing
@@ -474,7 +471,7 @@ impl BeaconState {
                     get_domain(
                         &self.fork_data,
                         proposer_slashing.proposal_data_2.slot,
-                        DOMAIN_PROPOSAL
+                        spec.domain_proposal
                     )
                 ),
                 BlockProcessingError::BadProposerSlashing
@@ -1576,9 +1573,8 @@ fn penalize_validator(_state: &BeaconState, _proposer_index: usize) {
     // TODO: stubbed out.
 }
 
-fn get_domain(_fork: &Fork, _slot: Slot, _domain_type: u64) -> u64 {
-    // TODO: stubbed out.
-    0
+fn get_domain(fork: &Fork, slot: Slot, domain_type: u64) -> u64 {
+    fork.get_domain(slot, domain_type)
 }
 
 fn bls_verify(pubkey: &PublicKey, message: &[u8], signature: &Signature, _domain: u64) -> bool {
@@ -1,23 +1,151 @@
 use crate::test_utils::TestRandom;
-use crate::{Hash256, Slot};
+use crate::{Epoch, Hash256, Slot};
 use rand::RngCore;
-use serde_derive::Serialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_derive::{Deserialize, Serialize};
 use ssz::{hash, Decodable, DecodeError, Encodable, SszStream, TreeHash};
+use std::cell::RefCell;
 
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// Ordered first by `slot`, then by `shard_block_root`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Crosslink {
     pub slot: Slot,
     pub shard_block_root: Hash256,
 }
 
 impl Crosslink {
-    /// Generates a new instance where `dynasty` and `hash` are both zero.
+    pub fn new(slot: Slot, shard_block_root: Hash256) -> Self {
+        Self {
+            slot,
+            shard_block_root,
+        }
+    }
+
+    /// Generates a new instance where `slot` and `shard_block_root` are both zero.
     pub fn zero() -> Self {
+        Self::new(Slot::from(0_u64), Hash256::zero())
+    }
+
+    /// Returns the epoch of the crosslink's `slot`.
+    pub fn epoch(&self, epoch_length: u64) -> Epoch {
+        self.slot.epoch(epoch_length)
+    }
+
+    /// Returns `true` if this crosslink is at a later slot than `other`.
+    pub fn is_newer_than(&self, other: &Crosslink) -> bool {
+        self.slot > other.slot
+    }
+}
+
+impl Default for Crosslink {
+    /// Equivalent to `Crosslink::zero()`.
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Wraps a `Crosslink` so that it serializes as a compact `[slot, "0xroot"]` array, instead of a
+/// map of named fields.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompactCrosslink(pub Crosslink);
+
+impl From<Crosslink> for CompactCrosslink {
+    fn from(crosslink: Crosslink) -> Self {
+        CompactCrosslink(crosslink)
+    }
+}
+
+impl From<CompactCrosslink> for Crosslink {
+    fn from(compact: CompactCrosslink) -> Self {
+        compact.0
+    }
+}
+
+impl Serialize for CompactCrosslink {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.0.slot, &self.0.shard_block_root).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactCrosslink {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (slot, shard_block_root) = <(Slot, Hash256)>::deserialize(deserializer)?;
+        Ok(CompactCrosslink(Crosslink {
+            slot,
+            shard_block_root,
+        }))
+    }
+}
+
+/// Wraps a `Crosslink`, caching its `hash_tree_root` so that an unchanged crosslink is not
+/// re-hashed.
+///
+/// The fields may only be mutated via the setters, which invalidate the cache.
+#[derive(Clone, Debug, Default)]
+pub struct CachedCrosslink {
+    crosslink: Crosslink,
+    root: RefCell<Option<Vec<u8>>>,
+}
+
+impl CachedCrosslink {
+    pub fn new(crosslink: Crosslink) -> Self {
         Self {
-            slot: Slot::from(0_u64),
-            shard_block_root: Hash256::zero(),
+            crosslink,
+            root: RefCell::new(None),
         }
     }
+
+    pub fn crosslink(&self) -> &Crosslink {
+        &self.crosslink
+    }
+
+    pub fn into_inner(self) -> Crosslink {
+        self.crosslink
+    }
+
+    pub fn set_slot(&mut self, slot: Slot) {
+        self.crosslink.slot = slot;
+        self.root.replace(None);
+    }
+
+    pub fn set_shard_block_root(&mut self, shard_block_root: Hash256) {
+        self.crosslink.shard_block_root = shard_block_root;
+        self.root.replace(None);
+    }
+
+    /// Returns `true` if the `hash_tree_root` has been computed since the last mutation.
+    pub fn is_cached(&self) -> bool {
+        self.root.borrow().is_some()
+    }
+}
+
+impl From<Crosslink> for CachedCrosslink {
+    fn from(crosslink: Crosslink) -> Self {
+        CachedCrosslink::new(crosslink)
+    }
+}
+
+impl PartialEq for CachedCrosslink {
+    fn eq(&self, other: &CachedCrosslink) -> bool {
+        self.crosslink == other.crosslink
+    }
+}
+
+impl TreeHash for CachedCrosslink {
+    fn hash_tree_root(&self) -> Vec<u8> {
+        self.root
+            .borrow_mut()
+            .get_or_insert_with(|| self.crosslink.hash_tree_root())
+            .clone()
+    }
+}
+
+/// Returns the crosslinks in `crosslinks` with a slot in the range `[from, to)`.
+pub fn within_range(crosslinks: &[Crosslink], from: Slot, to: Slot) -> Vec<&Crosslink> {
+    crosslinks
+        .iter()
+        .filter(|crosslink| crosslink.slot >= from && crosslink.slot < to)
+        .collect()
 }
 
 impl Encodable for Crosslink {
@@ -66,6 +194,58 @@ mod tests {
     use crate::test_utils::{SeedableRng, TestRandom, XorShiftRng};
     use ssz::ssz_encode;
 
+    #[test]
+    pub fn test_new() {
+        let root = Hash256::from(&[42; 32][..]);
+        let crosslink = Crosslink::new(Slot::new(7), root);
+
+        assert_eq!(crosslink.slot, Slot::new(7));
+        assert_eq!(crosslink.shard_block_root, root);
+    }
+
+    #[test]
+    pub fn test_zero() {
+        let crosslink = Crosslink::zero();
+
+        assert_eq!(crosslink.slot, Slot::new(0));
+        assert_eq!(crosslink.shard_block_root, Hash256::zero());
+        assert_eq!(crosslink, Crosslink::new(Slot::new(0), Hash256::zero()));
+    }
+
+    #[test]
+    pub fn test_epoch() {
+        let epoch_length = 64;
+        let crosslink = |slot: u64| Crosslink::new(Slot::new(slot), Hash256::zero());
+
+        assert_eq!(crosslink(0).epoch(epoch_length), Epoch::new(0));
+        assert_eq!(crosslink(63).epoch(epoch_length), Epoch::new(0));
+        assert_eq!(crosslink(64).epoch(epoch_length), Epoch::new(1));
+        assert_eq!(crosslink(200).epoch(epoch_length), Epoch::new(3));
+    }
+
+    #[test]
+    pub fn test_is_newer_than() {
+        let crosslink = |slot: u64| Crosslink::new(Slot::new(slot), Hash256::zero());
+
+        // In the same epoch.
+        assert!(crosslink(5).is_newer_than(&crosslink(4)));
+        assert!(!crosslink(4).is_newer_than(&crosslink(5)));
+        assert!(!crosslink(4).is_newer_than(&crosslink(4)));
+
+        // In different epochs.
+        assert!(crosslink(64).is_newer_than(&crosslink(63)));
+        assert!(!crosslink(63).is_newer_than(&crosslink(64)));
+
+        // The root is not considered.
+        let other_root = Crosslink::new(Slot::new(4), Hash256::from(&[1; 32][..]));
+        assert!(!other_root.is_newer_than(&crosslink(4)));
+    }
+
+    #[test]
+    pub fn test_default() {
+        assert_eq!(Crosslink::default(), Crosslink::zero());
+    }
+
     #[test]
     pub fn test_ssz_round_trip() {
         let mut rng = XorShiftRng::from_seed([42; 16]);
@@ -77,6 +257,152 @@ mod tests {
         assert_eq!(original, decoded);
     }
 
+    #[test]
+    pub fn test_ssz_encoded_length() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = Crosslink::random_for_test(&mut rng);
+
+        // `slot` (8 bytes) + `shard_block_root` (32 bytes).
+        //
+        // If this changes, the wire format of `Crosslink` has changed. Update it deliberately.
+        assert_eq!(ssz_encode(&original).len(), 8 + 32);
+        assert_eq!(ssz_encode(&Crosslink::zero()).len(), 8 + 32);
+    }
+
+    #[test]
+    pub fn test_json_round_trip() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = Crosslink::random_for_test(&mut rng);
+
+        let json = serde_json::to_string(&original).unwrap();
+        let decoded: Crosslink = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    pub fn test_ordering() {
+        let root = |byte: u8| Hash256::from(&[byte; 32][..]);
+        let crosslink = |slot: u64, byte: u8| Crosslink::new(Slot::new(slot), root(byte));
+
+        // Ordered by slot first, regardless of the root.
+        assert!(crosslink(1, 9) < crosslink(2, 0));
+        // Then by root, when the slots are equal.
+        assert!(crosslink(2, 0) < crosslink(2, 1));
+        assert_eq!(
+            crosslink(2, 1).cmp(&crosslink(2, 1)),
+            std::cmp::Ordering::Equal
+        );
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(crosslink(3, 0), "c");
+        map.insert(crosslink(1, 5), "a");
+        map.insert(crosslink(3, 1), "d");
+        map.insert(crosslink(2, 0), "b");
+        assert_eq!(
+            map.values().cloned().collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
+        );
+    }
+
+    #[test]
+    pub fn test_compact_json_round_trip() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = Crosslink::random_for_test(&mut rng);
+
+        let verbose = serde_json::to_string(&original).unwrap();
+        let compact = serde_json::to_string(&CompactCrosslink(original.clone())).unwrap();
+
+        assert!(compact.starts_with('['));
+        assert!(compact.len() < verbose.len());
+
+        let from_verbose: Crosslink = serde_json::from_str(&verbose).unwrap();
+        let from_compact: CompactCrosslink = serde_json::from_str(&compact).unwrap();
+
+        assert_eq!(from_verbose, original);
+        assert_eq!(Crosslink::from(from_compact), original);
+    }
+
+    #[test]
+    pub fn test_compact_json_format() {
+        let crosslink = Crosslink::new(Slot::new(42), Hash256::zero());
+
+        let compact = serde_json::to_string(&CompactCrosslink::from(crosslink)).unwrap();
+
+        assert_eq!(compact, format!("[42,\"0x{}\"]", "00".repeat(32)));
+    }
+
+    fn crosslinks_at_slots(slots: &[u64]) -> Vec<Crosslink> {
+        slots
+            .iter()
+            .map(|slot| Crosslink::new(Slot::new(*slot), Hash256::zero()))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_within_range_empty() {
+        let crosslinks = crosslinks_at_slots(&[1, 2, 3]);
+
+        assert!(within_range(&crosslinks, Slot::new(10), Slot::new(20)).is_empty());
+        assert!(within_range(&[], Slot::new(0), Slot::new(20)).is_empty());
+        // An empty range contains nothing.
+        assert!(within_range(&crosslinks, Slot::new(2), Slot::new(2)).is_empty());
+    }
+
+    #[test]
+    pub fn test_within_range_partial() {
+        let crosslinks = crosslinks_at_slots(&[1, 5, 7, 12, 6]);
+
+        let result = within_range(&crosslinks, Slot::new(5), Slot::new(10));
+
+        assert_eq!(result, vec![&crosslinks[1], &crosslinks[2], &crosslinks[4]]);
+    }
+
+    #[test]
+    pub fn test_within_range_boundaries() {
+        let crosslinks = crosslinks_at_slots(&[4, 5, 9, 10]);
+
+        let result = within_range(&crosslinks, Slot::new(5), Slot::new(10));
+
+        // `from` is inclusive, `to` is exclusive.
+        assert_eq!(result, vec![&crosslinks[1], &crosslinks[2]]);
+    }
+
+    #[test]
+    pub fn test_cached_hash_tree_root() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let mut cached: Vec<CachedCrosslink> = (0..1_000)
+            .map(|_| CachedCrosslink::new(Crosslink::random_for_test(&mut rng)))
+            .collect();
+
+        // Hash each crosslink repeatedly, as if across many epochs.
+        for _ in 0..3 {
+            for crosslink in &cached {
+                assert_eq!(
+                    crosslink.hash_tree_root(),
+                    crosslink.crosslink().hash_tree_root()
+                );
+                assert!(crosslink.is_cached());
+            }
+        }
+
+        // Mutation invalidates the cache.
+        cached[0].set_slot(Slot::new(42));
+        assert!(!cached[0].is_cached());
+        assert_eq!(
+            cached[0].hash_tree_root(),
+            cached[0].crosslink().hash_tree_root()
+        );
+
+        cached[1].set_shard_block_root(Hash256::zero());
+        assert!(!cached[1].is_cached());
+        assert_eq!(
+            cached[1].hash_tree_root(),
+            cached[1].crosslink().hash_tree_root()
+        );
+        assert!(cached[1].is_cached());
+    }
+
     #[test]
     pub fn test_hash_tree_root() {
         let mut rng = XorShiftRng::from_seed([42; 16]);
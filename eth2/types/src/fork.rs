@@ -10,6 +10,25 @@ pub struct Fork {
     pub fork_slot: Slot,
 }
 
+impl Fork {
+    /// Returns the fork version in effect at the given `slot`.
+    pub fn get_fork_version(&self, slot: Slot) -> u64 {
+        if slot < self.fork_slot {
+            self.pre_fork_version
+        } else {
+            self.post_fork_version
+        }
+    }
+
+    /// Returns the signature domain for the given `slot` and `domain_type`.
+    ///
+    /// The fork version forms the upper 32 bits of the domain, ensuring that messages signed
+    /// either side of a fork are not interchangeable.
+    pub fn get_domain(&self, slot: Slot, domain_type: u64) -> u64 {
+        (self.get_fork_version(slot) << 32).wrapping_add(domain_type)
+    }
+}
+
 impl Encodable for Fork {
     fn ssz_append(&self, s: &mut SszStream) {
         s.append(&self.pre_fork_version);
@@ -61,6 +80,36 @@ mod tests {
     use crate::test_utils::{SeedableRng, TestRandom, XorShiftRng};
     use ssz::ssz_encode;
 
+    #[test]
+    pub fn get_fork_version() {
+        let fork = Fork {
+            pre_fork_version: 1,
+            post_fork_version: 2,
+            fork_slot: Slot::new(10),
+        };
+
+        assert_eq!(fork.get_fork_version(Slot::new(0)), 1);
+        assert_eq!(fork.get_fork_version(Slot::new(9)), 1);
+        assert_eq!(fork.get_fork_version(Slot::new(10)), 2);
+        assert_eq!(fork.get_fork_version(Slot::new(11)), 2);
+    }
+
+    #[test]
+    pub fn get_domain() {
+        let fork = Fork {
+            pre_fork_version: 1,
+            post_fork_version: 2,
+            fork_slot: Slot::new(10),
+        };
+
+        assert_eq!(fork.get_domain(Slot::new(9), 4), (1 << 32) + 4);
+        assert_eq!(fork.get_domain(Slot::new(10), 4), (2 << 32) + 4);
+        assert_ne!(
+            fork.get_domain(Slot::new(9), 4),
+            fork.get_domain(Slot::new(10), 4)
+        );
+    }
+
     #[test]
     pub fn test_ssz_round_trip() {
         let mut rng = XorShiftRng::from_seed([42; 16]);
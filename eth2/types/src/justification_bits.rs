@@ -0,0 +1,198 @@
+use crate::test_utils::TestRandom;
+use rand::RngCore;
+use serde_derive::{Deserialize, Serialize};
+use ssz::{hash, Decodable, DecodeError, Encodable, SszStream, TreeHash};
+
+/// The number of epochs tracked by `JustificationBits`.
+pub const JUSTIFICATION_BITS_LENGTH: usize = 64;
+
+/// The justification status of recent epochs, where bit `i` is set if the epoch `i` epochs prior
+/// to the current epoch was justified.
+///
+/// Encodes identically to the `u64` `BeaconState::justification_bitfield`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct JustificationBits(u64);
+
+impl JustificationBits {
+    /// Sets the bit for the epoch `epoch_offset` epochs prior to the current epoch.
+    ///
+    /// Has no effect if `epoch_offset >= JUSTIFICATION_BITS_LENGTH`.
+    pub fn set(&mut self, epoch_offset: usize) {
+        if epoch_offset < JUSTIFICATION_BITS_LENGTH {
+            self.0 |= 1 << epoch_offset;
+        }
+    }
+
+    /// Clears the bit for the epoch `epoch_offset` epochs prior to the current epoch.
+    ///
+    /// Has no effect if `epoch_offset >= JUSTIFICATION_BITS_LENGTH`.
+    pub fn clear(&mut self, epoch_offset: usize) {
+        if epoch_offset < JUSTIFICATION_BITS_LENGTH {
+            self.0 &= !(1 << epoch_offset);
+        }
+    }
+
+    /// Returns `true` if the epoch `epoch_offset` epochs prior to the current epoch was
+    /// justified.
+    ///
+    /// Returns `false` if `epoch_offset >= JUSTIFICATION_BITS_LENGTH`.
+    pub fn get(&self, epoch_offset: usize) -> bool {
+        epoch_offset < JUSTIFICATION_BITS_LENGTH && (self.0 >> epoch_offset) & 1 == 1
+    }
+
+    /// Moves each bit one epoch further into the past, as is done at each epoch transition.
+    ///
+    /// The bit for the current epoch is cleared and the oldest bit is discarded.
+    pub fn shift(&mut self) {
+        self.0 <<= 1;
+    }
+
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for JustificationBits {
+    fn from(bits: u64) -> JustificationBits {
+        JustificationBits(bits)
+    }
+}
+
+impl Into<u64> for JustificationBits {
+    fn into(self) -> u64 {
+        self.0
+    }
+}
+
+impl Encodable for JustificationBits {
+    fn ssz_append(&self, s: &mut SszStream) {
+        s.append(&self.0);
+    }
+}
+
+impl Decodable for JustificationBits {
+    fn ssz_decode(bytes: &[u8], i: usize) -> Result<(Self, usize), DecodeError> {
+        let (bits, i) = <_>::ssz_decode(bytes, i)?;
+
+        Ok((JustificationBits(bits), i))
+    }
+}
+
+impl TreeHash for JustificationBits {
+    fn hash_tree_root(&self) -> Vec<u8> {
+        let mut result: Vec<u8> = vec![];
+        result.append(&mut self.0.hash_tree_root());
+        hash(&result)
+    }
+}
+
+impl<T: RngCore> TestRandom<T> for JustificationBits {
+    fn random_for_test(rng: &mut T) -> Self {
+        JustificationBits(<_>::random_for_test(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{SeedableRng, TestRandom, XorShiftRng};
+    use ssz::ssz_encode;
+
+    #[test]
+    pub fn test_set_and_clear() {
+        let mut bits = JustificationBits::default();
+        assert!(!bits.get(0));
+
+        bits.set(0);
+        bits.set(3);
+        bits.set(63);
+        assert!(bits.get(0));
+        assert!(!bits.get(1));
+        assert!(bits.get(3));
+        assert!(bits.get(63));
+        assert_eq!(bits.as_u64(), 1 | 1 << 3 | 1 << 63);
+
+        bits.clear(3);
+        bits.clear(1);
+        assert!(bits.get(0));
+        assert!(!bits.get(1));
+        assert!(!bits.get(3));
+        assert_eq!(bits.as_u64(), 1 | 1 << 63);
+    }
+
+    #[test]
+    pub fn test_out_of_range() {
+        let mut bits = JustificationBits::default();
+
+        bits.set(JUSTIFICATION_BITS_LENGTH);
+        assert_eq!(bits, JustificationBits::default());
+        assert!(!bits.get(JUSTIFICATION_BITS_LENGTH));
+
+        let mut bits = JustificationBits::from(u64::max_value());
+        bits.clear(JUSTIFICATION_BITS_LENGTH);
+        assert_eq!(bits.as_u64(), u64::max_value());
+    }
+
+    #[test]
+    pub fn test_shift() {
+        let mut bits = JustificationBits::default();
+        bits.set(0);
+        bits.set(2);
+
+        bits.shift();
+
+        // The current epoch is no longer justified and each bit has aged by one epoch.
+        assert!(!bits.get(0));
+        assert!(bits.get(1));
+        assert!(!bits.get(2));
+        assert!(bits.get(3));
+
+        // The oldest bit is discarded.
+        let mut bits = JustificationBits::default();
+        bits.set(63);
+        bits.shift();
+        assert_eq!(bits, JustificationBits::default());
+    }
+
+    #[test]
+    pub fn test_matches_u64_bitfield() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = JustificationBits::random_for_test(&mut rng);
+
+        let mut bits = original;
+        bits.shift();
+        bits.set(1);
+        bits.set(0);
+
+        // As per the `u64` bitfield arithmetic in `BeaconState::per_epoch_processing`.
+        let (mut bitfield, _) = original.as_u64().overflowing_mul(2);
+        bitfield |= 2;
+        bitfield |= 1;
+
+        assert_eq!(bits.as_u64(), bitfield);
+        assert_eq!(ssz_encode(&bits), ssz_encode(&bitfield));
+    }
+
+    #[test]
+    pub fn test_ssz_round_trip() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = JustificationBits::random_for_test(&mut rng);
+
+        let bytes = ssz_encode(&original);
+        let (decoded, _) = <_>::ssz_decode(&bytes, 0).unwrap();
+
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    pub fn test_hash_tree_root() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = JustificationBits::random_for_test(&mut rng);
+
+        let result = original.hash_tree_root();
+
+        assert_eq!(result.len(), 32);
+        // TODO: Add further tests
+        // https://github.com/sigp/lighthouse/issues/170
+    }
+}
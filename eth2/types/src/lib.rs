@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "step_trait", feature(step_trait))]
+
 pub mod test_utils;
 
 pub mod attestation;
@@ -16,6 +18,7 @@ pub mod eth1_data_vote;
 pub mod exit;
 pub mod fork;
 pub mod free_attestation;
+pub mod justification_bits;
 pub mod pending_attestation;
 pub mod proposal_signed_data;
 pub mod proposer_slashing;
@@ -26,6 +29,7 @@ pub mod slashable_vote_data;
 pub mod slot_epoch;
 pub mod spec;
 pub mod special_record;
+pub mod sync_committee_message;
 pub mod validator;
 pub mod validator_registry;
 pub mod validator_registry_delta_block;
@@ -40,7 +44,7 @@ pub use crate::beacon_block::BeaconBlock;
 pub use crate::beacon_block_body::BeaconBlockBody;
 pub use crate::beacon_state::BeaconState;
 pub use crate::casper_slashing::CasperSlashing;
-pub use crate::crosslink::Crosslink;
+pub use crate::crosslink::{CachedCrosslink, CompactCrosslink, Crosslink};
 pub use crate::deposit::Deposit;
 pub use crate::deposit_data::DepositData;
 pub use crate::deposit_input::DepositInput;
@@ -49,14 +53,16 @@ pub use crate::eth1_data_vote::Eth1DataVote;
 pub use crate::exit::Exit;
 pub use crate::fork::Fork;
 pub use crate::free_attestation::FreeAttestation;
+pub use crate::justification_bits::JustificationBits;
 pub use crate::pending_attestation::PendingAttestation;
 pub use crate::proposal_signed_data::ProposalSignedData;
 pub use crate::proposer_slashing::ProposerSlashing;
 pub use crate::shard_committee::ShardCommittee;
 pub use crate::slashable_vote_data::SlashableVoteData;
-pub use crate::slot_epoch::{Epoch, Slot};
+pub use crate::slot_epoch::{Epoch, HexParseError, Slot, SlotEpochContext, SlotError};
 pub use crate::spec::ChainSpec;
 pub use crate::special_record::{SpecialRecord, SpecialRecordKind};
+pub use crate::sync_committee_message::SyncCommitteeMessage;
 pub use crate::validator::{StatusFlags as ValidatorStatusFlags, Validator};
 pub use crate::validator_registry_delta_block::ValidatorRegistryDeltaBlock;
 
@@ -2,7 +2,7 @@ use crate::test_utils::TestRandom;
 use crate::{Hash256, Slot};
 use rand::RngCore;
 use serde_derive::Serialize;
-use ssz::{hash, Decodable, DecodeError, Encodable, SszStream, TreeHash};
+use ssz::{hash, ssz_encode, Decodable, DecodeError, Encodable, SszStream, TreeHash};
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize)]
 pub struct ProposalSignedData {
@@ -11,6 +11,18 @@ pub struct ProposalSignedData {
     pub block_root: Hash256,
 }
 
+impl ProposalSignedData {
+    /// Returns the root which is signed to make this proposal.
+    ///
+    /// The root is the hash of the `hash_tree_root` concatenated with the SSZ-encoded signature
+    /// `domain`.
+    pub fn signing_root(&self, domain: u64) -> Hash256 {
+        let mut message = self.hash_tree_root();
+        message.append(&mut ssz_encode(&domain));
+        Hash256::from(&hash(&message)[..])
+    }
+}
+
 impl Encodable for ProposalSignedData {
     fn ssz_append(&self, s: &mut SszStream) {
         s.append(&self.slot);
@@ -60,7 +72,6 @@ impl<T: RngCore> TestRandom<T> for ProposalSignedData {
 mod tests {
     use super::*;
     use crate::test_utils::{SeedableRng, TestRandom, XorShiftRng};
-    use ssz::ssz_encode;
 
     #[test]
     pub fn test_ssz_round_trip() {
@@ -84,4 +95,17 @@ mod tests {
         // TODO: Add further tests
         // https://github.com/sigp/lighthouse/issues/170
     }
+
+    #[test]
+    pub fn test_signing_root() {
+        let mut rng = XorShiftRng::from_seed([42; 16]);
+        let original = ProposalSignedData::random_for_test(&mut rng);
+
+        assert_eq!(original.signing_root(2), original.signing_root(2));
+        assert_ne!(original.signing_root(2), original.signing_root(3));
+        assert_ne!(
+            original.signing_root(2),
+            Hash256::from(&original.hash_tree_root()[..])
+        );
+    }
 }
@@ -10,14 +10,19 @@
 /// implement `Into<u64>`, however this would allow operations between `Slots` and `Epochs` which
 /// may lead to programming errors which are not detected by the compiler.
 use crate::test_utils::TestRandom;
+use crate::Hash256;
+use num_traits::{One, Zero};
 use rand::RngCore;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 use slog;
-use ssz::{hash, Decodable, DecodeError, Encodable, SszStream, TreeHash};
+use ssz::{hash, ssz_encode, Decodable, DecodeError, Encodable, SszStream, TreeHash};
 use std::cmp::{Ord, Ordering};
+use std::convert::TryFrom;
 use std::fmt;
-use std::iter::Iterator;
+use std::iter::{successors, Iterator, Sum};
+use std::num::TryFromIntError;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, Sub, SubAssign};
+use std::time::Duration;
 
 macro_rules! impl_from_into_u64 {
     ($main: ident) => {
@@ -37,6 +42,22 @@ macro_rules! impl_from_into_u64 {
             pub fn as_u64(&self) -> u64 {
                 self.0
             }
+
+            pub fn from_le_bytes(bytes: [u8; 8]) -> $main {
+                $main(u64::from_le_bytes(bytes))
+            }
+
+            pub fn to_le_bytes(&self) -> [u8; 8] {
+                self.0.to_le_bytes()
+            }
+
+            pub fn from_be_bytes(bytes: [u8; 8]) -> $main {
+                $main(u64::from_be_bytes(bytes))
+            }
+
+            pub fn to_be_bytes(&self) -> [u8; 8] {
+                self.0.to_be_bytes()
+            }
         }
     };
 }
@@ -49,16 +70,56 @@ macro_rules! impl_from_into_usize {
             }
         }
 
-        impl Into<usize> for $main {
-            fn into(self) -> usize {
-                self.0 as usize
+        /// Fails if the value exceeds `usize::max_value()` (i.e., on 32-bit systems).
+        impl TryFrom<$main> for usize {
+            type Error = TryFromIntError;
+
+            fn try_from(n: $main) -> Result<usize, TryFromIntError> {
+                usize::try_from(n.0)
             }
         }
 
         impl $main {
+            /// Lossy conversion: values exceeding `usize::max_value()` are truncated on 32-bit
+            /// systems. See `try_as_usize` for a checked conversion.
             pub fn as_usize(&self) -> usize {
                 self.0 as usize
             }
+
+            /// Returns an error if the value exceeds `usize::max_value()`.
+            pub fn try_as_usize(&self) -> Result<usize, TryFromIntError> {
+                usize::try_from(*self)
+            }
+        }
+    };
+}
+
+/// Implements `$trait` for each combination of owned and borrowed operands, by forwarding to the
+/// owned implementation.
+macro_rules! impl_ref_op {
+    ($main: ident, $other: ident, $trait: ident, $method: ident) => {
+        impl<'a> $trait<&'a $other> for $main {
+            type Output = $main;
+
+            fn $method(self, other: &'a $other) -> $main {
+                <$main as $trait<$other>>::$method(self, *other)
+            }
+        }
+
+        impl<'a> $trait<$other> for &'a $main {
+            type Output = $main;
+
+            fn $method(self, other: $other) -> $main {
+                <$main as $trait<$other>>::$method(*self, other)
+            }
+        }
+
+        impl<'a, 'b> $trait<&'b $other> for &'a $main {
+            type Output = $main;
+
+            fn $method(self, other: &'b $other) -> $main {
+                <$main as $trait<$other>>::$method(*self, *other)
+            }
         }
     };
 }
@@ -153,16 +214,88 @@ macro_rules! impl_math_between {
                 $main::from(self.0 % modulus)
             }
         }
+
+        impl_ref_op!($main, $other, Add, add);
+        impl_ref_op!($main, $other, Sub, sub);
+        impl_ref_op!($main, $other, Mul, mul);
+        impl_ref_op!($main, $other, Div, div);
+        impl_ref_op!($main, $other, Rem, rem);
     };
 }
 
 macro_rules! impl_math {
     ($type: ident) => {
         impl $type {
+            /// The value at genesis (i.e., zero).
+            pub const GENESIS: $type = $type(0);
+
+            pub fn is_genesis(&self) -> bool {
+                *self == $type::GENESIS
+            }
+
             pub fn saturating_sub<T: Into<$type>>(&self, other: T) -> $type {
                 *self - other.into()
             }
 
+            pub fn saturating_add<T: Into<$type>>(&self, other: T) -> $type {
+                *self + other.into()
+            }
+
+            pub fn saturating_mul<T: Into<$type>>(&self, rhs: T) -> $type {
+                *self * rhs.into()
+            }
+
+            /// Raises `self` to the power of `exp`, saturating at the maximum value.
+            pub fn pow(&self, exp: u32) -> $type {
+                $type(self.0.saturating_pow(exp))
+            }
+
+            pub fn min_value() -> $type {
+                $type(0)
+            }
+
+            /// Restricts the value to the range `[lo, hi]`.
+            ///
+            /// Panics in debug builds if `lo > hi`.
+            pub fn clamp(self, lo: $type, hi: $type) -> $type {
+                debug_assert!(lo <= hi, "clamp requires lo <= hi");
+                if self < lo {
+                    lo
+                } else if self > hi {
+                    hi
+                } else {
+                    self
+                }
+            }
+
+            /// Returns `self + 1`, saturating at the maximum value.
+            pub const fn next(&self) -> $type {
+                $type(self.0.saturating_add(1))
+            }
+
+            /// Returns `self - 1`, saturating at zero.
+            pub const fn previous(&self) -> $type {
+                $type(self.0.saturating_sub(1))
+            }
+
+            /// Returns `None` if the addition overflows, instead of saturating.
+            pub fn checked_add<T: Into<$type>>(&self, other: T) -> Option<$type> {
+                let other: $type = other.into();
+                self.0.checked_add(other.0).map($type::from)
+            }
+
+            /// Returns `None` if the subtraction underflows, instead of saturating.
+            pub fn checked_sub<T: Into<$type>>(&self, other: T) -> Option<$type> {
+                let other: $type = other.into();
+                self.0.checked_sub(other.0).map($type::from)
+            }
+
+            /// Returns `None` if the multiplication overflows, instead of saturating.
+            pub fn checked_mul<T: Into<$type>>(&self, rhs: T) -> Option<$type> {
+                let rhs: $type = rhs.into();
+                self.0.checked_mul(rhs.0).map($type::from)
+            }
+
             pub fn checked_div<T: Into<$type>>(&self, rhs: T) -> Option<$type> {
                 let rhs: $type = rhs.into();
                 if rhs == 0 {
@@ -175,6 +308,22 @@ macro_rules! impl_math {
             pub fn is_power_of_two(&self) -> bool {
                 self.0.is_power_of_two()
             }
+
+            /// Returns an iterator yielding `self, self + step, self + 2 * step, ...` whilst less
+            /// than `end`.
+            ///
+            /// Yields nothing if `step == 0`.
+            pub fn step_iter(&self, step: u64, end: $type) -> impl Iterator<Item = $type> {
+                let first = Some(*self).filter(|first| step != 0 && *first < end);
+
+                successors(first, move |previous| {
+                    previous
+                        .0
+                        .checked_add(step)
+                        .map($type::from)
+                        .filter(|next| *next < end)
+                })
+            }
         }
 
         impl Ord for $type {
@@ -194,6 +343,68 @@ macro_rules! impl_display {
             }
         }
 
+        impl $type {
+            /// Returns the value as a string in the given `radix`, using lowercase letters for
+            /// digits above 9 (e.g., `Slot(255).to_radix_string(16) == Some("ff")`).
+            ///
+            /// Returns `None` if `radix` is not in the range `2..=36`.
+            pub fn to_radix_string(&self, radix: u32) -> Option<String> {
+                if radix < 2 || radix > 36 {
+                    return None;
+                }
+
+                // A `u64` has at most 64 digits, in base 2. Digits are written from the end.
+                let radix = u64::from(radix);
+                let mut digits = [0u8; 64];
+                let mut start = digits.len();
+                let mut value = self.0;
+                loop {
+                    start -= 1;
+                    digits[start] = std::char::from_digit((value % radix) as u32, radix as u32)
+                        .expect("remainder is less than radix") as u8;
+                    value /= radix;
+                    if value == 0 {
+                        break;
+                    }
+                }
+                Some(
+                    std::str::from_utf8(&digits[start..])
+                        .expect("digits are ASCII")
+                        .to_string(),
+                )
+            }
+
+            /// Returns the value as a `0x`-prefixed, lowercase hex string (e.g., `"0xff"`).
+            pub fn to_hex(&self) -> String {
+                format!("{:#x}", self.0)
+            }
+
+            /// Parses a hex string, with or without a `0x` prefix. The inverse of `to_hex`.
+            ///
+            /// Only hex digits are permitted after the prefix (i.e., no sign).
+            pub fn from_hex(hex: &str) -> Result<$type, HexParseError> {
+                let digits = if hex.starts_with("0x") || hex.starts_with("0X") {
+                    &hex[2..]
+                } else {
+                    hex
+                };
+                if digits.is_empty() {
+                    return Err(HexParseError::Empty);
+                }
+
+                digits
+                    .chars()
+                    .try_fold(0u64, |value, c| {
+                        let digit = c.to_digit(16).ok_or(HexParseError::InvalidDigit(c))?;
+                        value
+                            .checked_mul(16)
+                            .and_then(|value| value.checked_add(u64::from(digit)))
+                            .ok_or(HexParseError::Overflow)
+                    })
+                    .map($type)
+            }
+        }
+
         impl slog::Value for $type {
             fn serialize(
                 &self,
@@ -207,8 +418,32 @@ macro_rules! impl_display {
     };
 }
 
+macro_rules! impl_slog_kv {
+    ($type: ident, $key: expr) => {
+        /// Allows the value to be logged with an implied key (e.g., `info!(log, "msg"; slot)`), so
+        /// the type of the value is preserved in structured logs.
+        impl slog::KV for $type {
+            fn serialize(
+                &self,
+                record: &slog::Record,
+                serializer: &mut slog::Serializer,
+            ) -> slog::Result {
+                slog::Value::serialize(self, record, $key, serializer)
+            }
+        }
+    };
+}
+
 macro_rules! impl_ssz {
     ($type: ident) => {
+        impl $type {
+            /// Returns the length (in bytes) of the SSZ encoding, which is fixed as it is that of
+            /// a `u64`.
+            pub fn ssz_fixed_len() -> usize {
+                8
+            }
+        }
+
         impl Encodable for $type {
             fn ssz_append(&self, s: &mut SszStream) {
                 s.append(&self.0);
@@ -239,6 +474,66 @@ macro_rules! impl_ssz {
     };
 }
 
+macro_rules! impl_sum {
+    ($type: ident) => {
+        impl Sum<$type> for $type {
+            /// Saturates at the maximum value, consistent with `Add`.
+            fn sum<I: Iterator<Item = $type>>(iter: I) -> $type {
+                iter.fold($type(0), |a, b| a + b)
+            }
+        }
+
+        impl<'a> Sum<&'a $type> for $type {
+            /// Saturates at the maximum value, consistent with `Add`.
+            fn sum<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+                iter.fold($type(0), |a, b| a + *b)
+            }
+        }
+    };
+}
+
+macro_rules! impl_num_traits {
+    ($type: ident) => {
+        impl Zero for $type {
+            fn zero() -> Self {
+                $type(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl One for $type {
+            fn one() -> Self {
+                $type(1)
+            }
+        }
+    };
+}
+
+/// Permits `Slot` and `Epoch` to be used in ranges (e.g., `for slot in start..end`).
+///
+/// The `Step` trait is unstable, so this requires the `step_trait` feature and a nightly compiler.
+macro_rules! impl_step {
+    ($type: ident) => {
+        #[cfg(feature = "step_trait")]
+        impl std::iter::Step for $type {
+            fn steps_between(start: &$type, end: &$type) -> (usize, Option<usize>) {
+                u64::steps_between(&start.0, &end.0)
+            }
+
+            fn forward_checked(start: $type, count: usize) -> Option<$type> {
+                u64::forward_checked(start.0, count).map($type)
+            }
+
+            fn backward_checked(start: $type, count: usize) -> Option<$type> {
+                u64::backward_checked(start.0, count).map($type)
+            }
+        }
+    };
+}
+
 macro_rules! impl_common {
     ($type: ident) => {
         impl_from_into_u64!($type);
@@ -248,18 +543,61 @@ macro_rules! impl_common {
         impl_math!($type);
         impl_display!($type);
         impl_ssz!($type);
+        impl_sum!($type);
+        impl_num_traits!($type);
+        impl_step!($type);
     };
 }
 
-#[derive(Eq, Debug, Clone, Copy, Default, Serialize, Hash)]
+#[derive(Eq, Debug, Clone, Copy, Default, Serialize, Deserialize, Hash)]
 pub struct Slot(u64);
 
-#[derive(Eq, Debug, Clone, Copy, Default, Serialize, Hash)]
+#[derive(Eq, Debug, Clone, Copy, Default, Serialize, Deserialize, Hash)]
 pub struct Epoch(u64);
 
+/// An error converting between an `Epoch` and its `Slot`s.
+#[derive(Debug, PartialEq, Clone)]
+pub enum SlotError {
+    EpochLengthIsZero,
+    /// A slot of `epoch` would exceed `u64::max_value()` with the given `epoch_length`.
+    Overflow {
+        epoch: Epoch,
+        epoch_length: u64,
+    },
+}
+
+/// An error parsing a `Slot` or `Epoch` from a hex string.
+#[derive(Debug, PartialEq, Clone)]
+pub enum HexParseError {
+    /// There were no digits (after the `0x` prefix, if any).
+    Empty,
+    /// A character other than a hex digit was found.
+    InvalidDigit(char),
+    /// The value exceeds `u64::max_value()`.
+    Overflow,
+}
+
+impl fmt::Display for HexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexParseError::Empty => write!(f, "no hex digits"),
+            HexParseError::InvalidDigit(c) => write!(f, "invalid hex digit {:?}", c),
+            HexParseError::Overflow => write!(f, "hex value exceeds u64"),
+        }
+    }
+}
+
+/// Returns the maximum `Duration`, equal to `Duration::MAX` (which requires Rust 1.53).
+fn max_duration() -> Duration {
+    Duration::new(u64::max_value(), 999_999_999)
+}
+
 impl_common!(Slot);
 impl_common!(Epoch);
 
+impl_slog_kv!(Slot, "slot");
+impl_slog_kv!(Epoch, "epoch");
+
 impl Slot {
     pub fn new(slot: u64) -> Slot {
         Slot(slot)
@@ -269,9 +607,84 @@ impl Slot {
         Epoch::from(self.0 / epoch_length)
     }
 
+    /// Returns the offset of this slot within its epoch (`slot % epoch_length`).
+    ///
+    /// Returns `0` if `epoch_length == 0`.
+    pub fn position_in_epoch(&self, epoch_length: u64) -> u64 {
+        self.0.checked_rem(epoch_length).unwrap_or(0)
+    }
+
+    /// Returns `true` if this is the first slot of its epoch.
+    ///
+    /// Returns `false` if `epoch_length == 0`.
+    pub fn is_epoch_start(&self, epoch_length: u64) -> bool {
+        epoch_length != 0 && self.position_in_epoch(epoch_length) == 0
+    }
+
+    /// Returns `true` if this is the last slot of its epoch.
+    ///
+    /// Returns `false` if `epoch_length == 0`.
+    pub fn is_epoch_end(&self, epoch_length: u64) -> bool {
+        epoch_length != 0 && self.position_in_epoch(epoch_length) == epoch_length - 1
+    }
+
+    /// Returns the epoch of this slot, or `None` if `epoch_length == 0`.
+    pub fn checked_epoch(&self, epoch_length: u64) -> Option<Epoch> {
+        self.0.checked_div(epoch_length).map(Epoch::from)
+    }
+
     pub fn max_value() -> Slot {
         Slot(u64::max_value())
     }
+
+    /// Returns the UNIX timestamp (in seconds) at which this slot begins, given the UNIX
+    /// timestamp of genesis.
+    ///
+    /// Saturates at `u64::max_value()`.
+    pub fn start_time(&self, genesis_unix: u64, seconds_per_slot: u64) -> u64 {
+        genesis_unix.saturating_add(self.0.saturating_mul(seconds_per_slot))
+    }
+
+    /// Returns the slot occurring at the UNIX timestamp `ts` (in seconds), given the UNIX
+    /// timestamp of genesis. The inverse of `start_time`.
+    ///
+    /// Timestamps prior to genesis return slot zero, as does a `seconds_per_slot` of zero.
+    pub fn from_timestamp(ts: u64, genesis_unix: u64, seconds_per_slot: u64) -> Slot {
+        Slot(
+            ts.saturating_sub(genesis_unix)
+                .checked_div(seconds_per_slot)
+                .unwrap_or(0),
+        )
+    }
+
+    /// Returns the duration from genesis to the start of this slot.
+    ///
+    /// Saturates at the maximum `Duration`.
+    pub fn duration_since_genesis(&self, seconds_per_slot: u64) -> Duration {
+        self.0
+            .checked_mul(seconds_per_slot)
+            .map_or_else(max_duration, Duration::from_secs)
+    }
+
+    /// Returns `true` if this slot is no more than `ws_period_slots` slots prior to `head`, i.e.,
+    /// within the weak subjectivity period.
+    ///
+    /// Slots after `head` are always within the period.
+    pub fn is_within_ws_period(&self, head: Slot, ws_period_slots: u64) -> bool {
+        head.0.saturating_sub(self.0) <= ws_period_slots
+    }
+
+    /// Returns the number of slots from `other` to `self`, or zero if `other` is later.
+    pub fn slots_since(&self, other: Slot) -> u64 {
+        self.0.saturating_sub(other.0)
+    }
+
+    /// Returns `true` if this slot is one of the slots of `epoch`.
+    ///
+    /// Returns `false` if `epoch_length == 0`.
+    pub fn is_in_epoch(&self, epoch: Epoch, epoch_length: u64) -> bool {
+        self.checked_epoch(epoch_length) == Some(epoch)
+    }
 }
 
 impl Epoch {
@@ -279,10 +692,76 @@ impl Epoch {
         Epoch(slot)
     }
 
+    /// Returns the epoch of `slot`, as per `Slot::epoch`.
+    pub fn from_slot(slot: Slot, epoch_length: u64) -> Epoch {
+        slot.epoch(epoch_length)
+    }
+
     pub fn start_slot(&self, epoch_length: u64) -> Slot {
         Slot::from(self.0.saturating_mul(epoch_length))
     }
 
+    /// Returns the duration from genesis to the start of this epoch.
+    ///
+    /// Saturates at the maximum `Duration`.
+    pub fn duration_since_genesis(&self, seconds_per_slot: u64, epoch_length: u64) -> Duration {
+        self.0
+            .checked_mul(epoch_length)
+            .and_then(|slot| slot.checked_mul(seconds_per_slot))
+            .map_or_else(max_duration, Duration::from_secs)
+    }
+
+    /// Returns the number of epochs from `other` to `self`, or zero if `other` is later.
+    pub fn epochs_since(&self, other: Epoch) -> u64 {
+        self.0.saturating_sub(other.0)
+    }
+
+    /// Returns `true` if `slot` is one of the slots of this epoch, as per `Slot::is_in_epoch`.
+    pub fn contains_slot(&self, slot: Slot, epoch_length: u64) -> bool {
+        slot.is_in_epoch(*self, epoch_length)
+    }
+
+    /// Returns the first slot of this epoch, or `None` if `epoch_length == 0` or the slot would
+    /// overflow.
+    pub fn checked_start_slot(&self, epoch_length: u64) -> Option<Slot> {
+        if epoch_length == 0 {
+            None
+        } else {
+            self.0.checked_mul(epoch_length).map(Slot::from)
+        }
+    }
+
+    /// Returns the first slot of this epoch, or an error describing why it cannot be represented
+    /// (rather than saturating, as per `start_slot`).
+    pub fn start_slot_checked(&self, epoch_length: u64) -> Result<Slot, SlotError> {
+        if epoch_length == 0 {
+            return Err(SlotError::EpochLengthIsZero);
+        }
+
+        self.0
+            .checked_mul(epoch_length)
+            .map(Slot::from)
+            .ok_or(SlotError::Overflow {
+                epoch: *self,
+                epoch_length,
+            })
+    }
+
+    /// Returns the last slot of this epoch, or an error describing why it cannot be represented
+    /// (rather than saturating, as per `end_slot`).
+    pub fn end_slot_checked(&self, epoch_length: u64) -> Result<Slot, SlotError> {
+        let start_slot = self.start_slot_checked(epoch_length)?;
+
+        start_slot
+            .as_u64()
+            .checked_add(epoch_length - 1)
+            .map(Slot::from)
+            .ok_or(SlotError::Overflow {
+                epoch: *self,
+                epoch_length,
+            })
+    }
+
     pub fn end_slot(&self, epoch_length: u64) -> Slot {
         Slot::from(
             self.0
@@ -292,26 +771,118 @@ impl Epoch {
         )
     }
 
+    /// Returns an iterator over each of the `epoch_length` slots in this epoch.
     pub fn slot_iter(&self, epoch_length: u64) -> SlotIter {
         SlotIter {
-            current: self.start_slot(epoch_length),
-            epoch: self,
-            epoch_length,
+            start: self.start_slot(epoch_length),
+            remaining: epoch_length,
+        }
+    }
+
+    /// Returns an iterator over each slot from the start of `self` through to the end of
+    /// `end_epoch` (inclusive).
+    ///
+    /// The iterator is empty if `end_epoch < self`.
+    pub fn slot_iter_through(
+        &self,
+        end_epoch: Epoch,
+        epoch_length: u64,
+    ) -> impl Iterator<Item = Slot> {
+        self.iter_to(end_epoch + 1)
+            .flat_map(move |epoch| epoch.slot_iter(epoch_length))
+    }
+
+    /// Returns an iterator over each epoch from `self` up to (but not including) `end`.
+    ///
+    /// The iterator is empty if `end <= self`.
+    pub fn iter_to(&self, end: Epoch) -> EpochIter {
+        EpochIter {
+            current: *self,
+            end: end.max(*self),
         }
     }
+
+    /// Returns an iterator over each epoch from `self` up to (but not including) `end`, as per
+    /// `iter_to`, yielding each epoch with its `start_slot` and `end_slot`.
+    pub fn iter_with_bounds(
+        &self,
+        end: Epoch,
+        epoch_length: u64,
+    ) -> impl ExactSizeIterator<Item = (Epoch, Slot, Slot)> {
+        self.iter_to(end).map(move |epoch| {
+            (
+                epoch,
+                epoch.start_slot(epoch_length),
+                epoch.end_slot(epoch_length),
+            )
+        })
+    }
+
+    /// Returns the root which is signed to produce a RANDAO reveal for this epoch.
+    ///
+    /// The root is the hash of the SSZ-encoded epoch concatenated with the SSZ-encoded signature
+    /// `domain`.
+    pub fn randao_signing_root(&self, domain: u64) -> Hash256 {
+        let mut message = ssz_encode(self);
+        message.append(&mut ssz_encode(&domain));
+        Hash256::from(&hash(&message)[..])
+    }
 }
 
-pub struct SlotIter<'a> {
-    current: Slot,
-    epoch: &'a Epoch,
-    epoch_length: u64,
+/// Iterates the slots of an `Epoch`, from either end.
+pub struct SlotIter {
+    /// The next slot to be yielded from the front.
+    start: Slot,
+    /// The number of slots yet to be yielded, from either end.
+    remaining: u64,
 }
 
-impl<'a> Iterator for SlotIter<'a> {
+impl Iterator for SlotIter {
     type Item = Slot;
 
     fn next(&mut self) -> Option<Slot> {
-        if self.current == self.epoch.end_slot(self.epoch_length) {
+        if self.remaining == 0 {
+            None
+        } else {
+            let previous = self.start;
+            self.start += 1;
+            self.remaining -= 1;
+            Some(previous)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for SlotIter {
+    fn next_back(&mut self) -> Option<Slot> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            Some(self.start + self.remaining)
+        }
+    }
+}
+
+impl ExactSizeIterator for SlotIter {}
+
+/// Iterates a range of `Epoch`s.
+pub struct EpochIter {
+    /// The next epoch to be yielded.
+    current: Epoch,
+    /// The first epoch which will not be yielded.
+    end: Epoch,
+}
+
+impl Iterator for EpochIter {
+    type Item = Epoch;
+
+    fn next(&mut self) -> Option<Epoch> {
+        if self.current == self.end {
             None
         } else {
             let previous = self.current;
@@ -319,11 +890,80 @@ impl<'a> Iterator for SlotIter<'a> {
             Some(previous)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end.0 - self.current.0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for EpochIter {}
+
+/// Converts between `Slot`s and `Epoch`s with a fixed `epoch_length`, so it need not be passed to
+/// each conversion.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SlotEpochContext {
+    pub epoch_length: u64,
+}
+
+impl SlotEpochContext {
+    pub fn new(epoch_length: u64) -> Self {
+        Self { epoch_length }
+    }
+
+    /// As per `Slot::epoch`.
+    pub fn epoch_of(&self, slot: Slot) -> Epoch {
+        slot.epoch(self.epoch_length)
+    }
+
+    /// As per `Epoch::start_slot`.
+    pub fn start_slot_of(&self, epoch: Epoch) -> Slot {
+        epoch.start_slot(self.epoch_length)
+    }
+
+    /// As per `Epoch::slot_iter`.
+    pub fn slots_of(&self, epoch: Epoch) -> SlotIter {
+        epoch.slot_iter(self.epoch_length)
+    }
+}
+
+macro_rules! serde_hex_module {
+    ($module: ident, $type: ident) => {
+        pub mod $module {
+            use crate::slot_epoch::$type;
+            use serde::de::Error;
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S: Serializer>(
+                value: &$type,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&value.to_hex())
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$type, D::Error> {
+                let hex = String::deserialize(deserializer)?;
+                $type::from_hex(&hex).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+/// Serializes a `Slot` or `Epoch` as a `0x`-prefixed hex string (as per Ethereum JSON-RPC
+/// quantities), instead of a number.
+///
+/// Opt-in per field, e.g., `#[serde(with = "types::slot_epoch::serde_hex::slot")]`.
+pub mod serde_hex {
+    serde_hex_module!(slot, Slot);
+    serde_hex_module!(epoch, Epoch);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
 
     macro_rules! new_tests {
         ($type: ident) => {
@@ -505,11 +1145,71 @@ mod tests {
                 assert_rem(10, 100, 10);
                 assert_rem(302042, 3293, 2379);
             }
+
+            #[test]
+            fn borrowed_operands() {
+                let max = u64::max_value();
+                let a = $type(max - 1);
+                let b: $other = $type(2).into();
+
+                let assert_all =
+                    |owned: $type, owned_ref: $type, ref_owned: $type, ref_ref: $type| {
+                        assert_eq!(owned_ref, owned);
+                        assert_eq!(ref_owned, owned);
+                        assert_eq!(ref_ref, owned);
+                    };
+
+                // Each combination is equivalent to the owned operation, so remains saturating.
+                assert_all(a + b, a + &b, &a + b, &a + &b);
+                assert_eq!(&a + &b, $type(max));
+                assert_all(a - b, a - &b, &a - b, &a - &b);
+                assert_eq!(&$type(1) - &b, $type(0));
+
+                assert_all(a * b, a * &b, &a * b, &a * &b);
+                assert_eq!(&a * &b, $type(max));
+                assert_all(a / b, a / &b, &a / b, &a / &b);
+                assert_all(a % b, a % &b, &a % b, &a % &b);
+            }
+
+            #[test]
+            #[should_panic]
+            fn borrowed_div_panics_with_divide_by_zero() {
+                let other: $other = $type(0).into();
+                let _ = &$type(2) / &other;
+            }
         };
     }
 
     macro_rules! math_tests {
         ($type: ident) => {
+            #[test]
+            fn next() {
+                assert_eq!($type(0).next(), $type(1));
+                assert_eq!($type(41).next(), $type(42));
+                assert_eq!($type(u64::max_value() - 1).next(), $type(u64::max_value()));
+                assert_eq!($type(u64::max_value()).next(), $type(u64::max_value()));
+            }
+
+            #[test]
+            fn previous() {
+                assert_eq!($type(0).previous(), $type(0));
+                assert_eq!($type(1).previous(), $type(0));
+                assert_eq!($type(42).previous(), $type(41));
+                assert_eq!(
+                    $type(u64::max_value()).previous(),
+                    $type(u64::max_value() - 1)
+                );
+            }
+
+            #[test]
+            fn next_previous_const() {
+                const NEXT: $type = $type(1).next();
+                const PREVIOUS: $type = $type(1).previous();
+
+                assert_eq!(NEXT, $type(2));
+                assert_eq!(PREVIOUS, $type(0));
+            }
+
             #[test]
             fn saturating_sub() {
                 let assert_saturating_sub = |a: u64, b: u64, result: u64| {
@@ -528,23 +1228,114 @@ mod tests {
             }
 
             #[test]
-            fn checked_div() {
-                let assert_checked_div = |a: u64, b: u64, result: Option<u64>| {
-                    let division_result_as_u64 = match $type(a).checked_div($type(b)) {
-                        None => None,
-                        Some(val) => Some(val.as_u64()),
-                    };
-                    assert_eq!(division_result_as_u64, result);
+            fn saturating_add() {
+                let assert_saturating_add = |a: u64, b: u64, result: u64| {
+                    assert_eq!($type(a).saturating_add($type(b)), $type(result));
                 };
 
-                assert_checked_div(0, 2, Some(0));
-                assert_checked_div(2, 2, Some(1));
-                assert_checked_div(100, 50, Some(2));
-                assert_checked_div(128, 2, Some(64));
-                assert_checked_div(u64::max_value(), 2, Some(2_u64.pow(63) - 1));
+                assert_saturating_add(0, 1, 1);
+                assert_saturating_add(7, 7, 14);
+                assert_saturating_add(u64::max_value() - 1, 1, u64::max_value());
 
-                assert_checked_div(2, 0, None);
-                assert_checked_div(0, 0, None);
+                // Addition should be saturating
+                assert_saturating_add(u64::max_value(), 1, u64::max_value());
+                assert_saturating_add(u64::max_value(), u64::max_value(), u64::max_value());
+            }
+
+            #[test]
+            fn saturating_mul() {
+                let assert_saturating_mul = |a: u64, b: u64, result: u64| {
+                    assert_eq!($type(a).saturating_mul($type(b)), $type(result));
+                };
+
+                assert_saturating_mul(0, 2, 0);
+                assert_saturating_mul(2, 2, 4);
+                assert_saturating_mul(u64::max_value(), 1, u64::max_value());
+
+                // Multiplication should be saturating
+                assert_saturating_mul(u64::max_value(), 2, u64::max_value());
+                assert_saturating_mul(2_u64.pow(32), 2_u64.pow(32), u64::max_value());
+            }
+
+            #[test]
+            fn pow() {
+                assert_eq!($type(0).pow(0), $type(1));
+                assert_eq!($type(7).pow(0), $type(1));
+                assert_eq!($type(7).pow(1), $type(7));
+                assert_eq!($type(64).pow(2), $type(4096));
+                assert_eq!($type(2).pow(63), $type(2_u64.pow(63)));
+
+                // Exponentiation should be saturating
+                assert_eq!($type(2).pow(64), $type(u64::max_value()));
+                assert_eq!($type(u64::max_value()).pow(2), $type(u64::max_value()));
+            }
+
+            #[test]
+            fn checked_add() {
+                let assert_checked_add = |a: u64, b: u64, result: Option<u64>| {
+                    let sum = $type(a).checked_add($type(b)).map(|val| val.as_u64());
+                    assert_eq!(sum, result);
+                };
+
+                assert_checked_add(0, 0, Some(0));
+                assert_checked_add(1, 2, Some(3));
+                assert_checked_add(u64::max_value() - 1, 1, Some(u64::max_value()));
+                assert_checked_add(u64::max_value(), 0, Some(u64::max_value()));
+
+                assert_checked_add(u64::max_value(), 1, None);
+                assert_checked_add(u64::max_value(), u64::max_value(), None);
+            }
+
+            #[test]
+            fn checked_sub() {
+                let assert_checked_sub = |a: u64, b: u64, result: Option<u64>| {
+                    let difference = $type(a).checked_sub($type(b)).map(|val| val.as_u64());
+                    assert_eq!(difference, result);
+                };
+
+                assert_checked_sub(0, 0, Some(0));
+                assert_checked_sub(3, 2, Some(1));
+                assert_checked_sub(u64::max_value(), u64::max_value(), Some(0));
+
+                assert_checked_sub(0, 1, None);
+                assert_checked_sub(1, 2, None);
+                assert_checked_sub(0, u64::max_value(), None);
+            }
+
+            #[test]
+            fn checked_mul() {
+                let assert_checked_mul = |a: u64, b: u64, result: Option<u64>| {
+                    let product = $type(a).checked_mul($type(b)).map(|val| val.as_u64());
+                    assert_eq!(product, result);
+                };
+
+                assert_checked_mul(0, 2, Some(0));
+                assert_checked_mul(3, 2, Some(6));
+                assert_checked_mul(u64::max_value(), 1, Some(u64::max_value()));
+                assert_checked_mul(u64::max_value(), 0, Some(0));
+
+                assert_checked_mul(u64::max_value(), 2, None);
+                assert_checked_mul(2_u64.pow(32), 2_u64.pow(32), None);
+            }
+
+            #[test]
+            fn checked_div() {
+                let assert_checked_div = |a: u64, b: u64, result: Option<u64>| {
+                    let division_result_as_u64 = match $type(a).checked_div($type(b)) {
+                        None => None,
+                        Some(val) => Some(val.as_u64()),
+                    };
+                    assert_eq!(division_result_as_u64, result);
+                };
+
+                assert_checked_div(0, 2, Some(0));
+                assert_checked_div(2, 2, Some(1));
+                assert_checked_div(100, 50, Some(2));
+                assert_checked_div(128, 2, Some(64));
+                assert_checked_div(u64::max_value(), 2, Some(2_u64.pow(63) - 1));
+
+                assert_checked_div(2, 0, None);
+                assert_checked_div(0, 0, None);
                 assert_checked_div(u64::max_value(), 0, None);
             }
 
@@ -570,6 +1361,34 @@ mod tests {
                 assert_is_power_of_two(u64::max_value(), false);
             }
 
+            #[test]
+            fn step_iter() {
+                let collect = |start: u64, step: u64, end: u64| -> Vec<u64> {
+                    $type(start)
+                        .step_iter(step, $type(end))
+                        .map(|x| x.as_u64())
+                        .collect()
+                };
+
+                // A step of one is equivalent to a dense range.
+                assert_eq!(collect(3, 1, 9), (3..9).collect::<Vec<u64>>());
+                // A step of an epoch length yields the start of each epoch.
+                assert_eq!(collect(0, 64, 256), vec![0, 64, 128, 192]);
+                assert_eq!(collect(0, 64, 257), vec![0, 64, 128, 192, 256]);
+
+                // A zero step yields nothing.
+                assert_eq!(collect(0, 0, 10), vec![]);
+                // An `end` which is not greater than the start yields nothing.
+                assert_eq!(collect(5, 1, 5), vec![]);
+                assert_eq!(collect(5, 1, 0), vec![]);
+
+                // Stepping should stop, not wrap, at the maximum value.
+                assert_eq!(
+                    collect(u64::max_value() - 2, 2, u64::max_value()),
+                    vec![u64::max_value() - 2]
+                );
+            }
+
             #[test]
             fn ord() {
                 let assert_ord = |a: u64, ord: Ordering, b: u64| {
@@ -581,6 +1400,43 @@ mod tests {
                 assert_ord(0, Ordering::Less, u64::max_value());
                 assert_ord(u64::max_value(), Ordering::Greater, 0);
             }
+
+            #[test]
+            fn genesis() {
+                assert_eq!($type::GENESIS, $type(0));
+                assert!($type::GENESIS.is_genesis());
+                assert!($type(0).is_genesis());
+                assert!(!$type(1).is_genesis());
+                assert!(!$type(u64::max_value()).is_genesis());
+            }
+
+            #[test]
+            fn min_value() {
+                assert_eq!($type::min_value(), $type(0));
+            }
+
+            #[test]
+            fn clamp_to_range() {
+                let clamp = |x: u64, lo: u64, hi: u64| $type(x).clamp($type(lo), $type(hi));
+
+                // Below the range.
+                assert_eq!(clamp(1, 5, 10), $type(5));
+                // Inside the range, including its bounds.
+                assert_eq!(clamp(5, 5, 10), $type(5));
+                assert_eq!(clamp(7, 5, 10), $type(7));
+                assert_eq!(clamp(10, 5, 10), $type(10));
+                // Above the range.
+                assert_eq!(clamp(u64::max_value(), 5, 10), $type(10));
+                // An empty range.
+                assert_eq!(clamp(7, 5, 5), $type(5));
+            }
+
+            #[test]
+            #[should_panic]
+            #[cfg(debug_assertions)]
+            fn clamp_to_inverted_range() {
+                $type(7).clamp($type(10), $type(5));
+            }
         };
     }
 
@@ -597,6 +1453,22 @@ mod tests {
                 assert_eq!(original, decoded);
             }
 
+            #[test]
+            pub fn test_ssz_fixed_len() {
+                let mut rng = XorShiftRng::from_seed([42; 16]);
+
+                assert_eq!($type::ssz_fixed_len(), 8);
+                for _ in 0..32 {
+                    let value = $type::random_for_test(&mut rng);
+                    assert_eq!(ssz_encode(&value).len(), $type::ssz_fixed_len());
+                }
+                assert_eq!(ssz_encode(&$type(0)).len(), $type::ssz_fixed_len());
+                assert_eq!(
+                    ssz_encode(&$type(u64::max_value())).len(),
+                    $type::ssz_fixed_len()
+                );
+            }
+
             #[test]
             pub fn test_hash_tree_root() {
                 let mut rng = XorShiftRng::from_seed([42; 16]);
@@ -611,12 +1483,311 @@ mod tests {
         };
     }
 
+    macro_rules! serde_tests {
+        ($type: ident) => {
+            #[test]
+            pub fn test_json_round_trip() {
+                let original = $type(42);
+
+                let json = serde_json::to_string(&original).unwrap();
+                assert_eq!(json, "42");
+
+                let decoded: $type = serde_json::from_str(&json).unwrap();
+                assert_eq!(original, decoded);
+            }
+
+            #[test]
+            pub fn test_json_map_key_round_trip() {
+                let mut original: HashMap<$type, u64> = HashMap::new();
+                original.insert($type(12), 34);
+
+                // JSON object keys are always strings, however they must decode back into integers.
+                let json = serde_json::to_string(&original).unwrap();
+                assert_eq!(json, r#"{"12":34}"#);
+
+                let decoded: HashMap<$type, u64> = serde_json::from_str(&json).unwrap();
+                assert_eq!(original, decoded);
+                assert_eq!(decoded.get(&$type(12)), Some(&34));
+            }
+        };
+    }
+
+    macro_rules! num_traits_tests {
+        ($type: ident) => {
+            #[test]
+            fn zero() {
+                assert!($type::zero().is_zero());
+                assert_eq!($type::zero(), $type::new(0));
+                assert!(!$type::new(1).is_zero());
+            }
+
+            #[test]
+            fn one() {
+                assert_eq!($type::one(), $type::new(1));
+                assert_eq!($type::new(7) * $type::one(), $type::new(7));
+            }
+
+            #[test]
+            fn generic_sum() {
+                fn sum<T: Zero + Copy>(values: &[T]) -> T {
+                    values.iter().fold(T::zero(), |acc, x| acc + *x)
+                }
+
+                assert_eq!(sum::<$type>(&[]), $type::zero());
+                assert_eq!(
+                    sum(&[$type::new(1), $type::new(2), $type::new(3)]),
+                    $type::new(6)
+                );
+            }
+        };
+    }
+
+    #[cfg(feature = "step_trait")]
+    macro_rules! step_tests {
+        ($type: ident) => {
+            use std::iter::Step;
+
+            #[test]
+            fn range() {
+                let range: Vec<$type> = ($type(3)..$type(8)).collect();
+                let manual: Vec<$type> = (3..8).map($type).collect();
+
+                assert_eq!(range, manual);
+                assert_eq!(($type(3)..=$type(8)).count(), 6);
+            }
+
+            #[test]
+            fn empty_range() {
+                assert_eq!(($type(5)..$type(5)).count(), 0);
+                assert_eq!(($type(5)..$type(5)).next(), None);
+            }
+
+            #[test]
+            fn reversed_range() {
+                // A range where `start > end` is empty.
+                assert_eq!(($type(8)..$type(3)).count(), 0);
+
+                let reversed: Vec<$type> = ($type(3)..$type(8)).rev().collect();
+                let manual: Vec<$type> = (3..8).rev().map($type).collect();
+                assert_eq!(reversed, manual);
+            }
+
+            #[test]
+            fn steps_between() {
+                assert_eq!($type::steps_between(&$type(3), &$type(8)), (5, Some(5)));
+                assert_eq!($type::steps_between(&$type(8), &$type(3)), (0, None));
+            }
+
+            #[test]
+            fn forward_and_backward_checked() {
+                assert_eq!($type::forward_checked($type(3), 5), Some($type(8)));
+                assert_eq!($type::forward_checked($type(u64::max_value()), 1), None);
+                assert_eq!($type::backward_checked($type(8), 5), Some($type(3)));
+                assert_eq!($type::backward_checked($type(0), 1), None);
+            }
+        };
+    }
+
+    /// The number of random operand pairs tried by each fuzz test.
+    const FUZZ_ITERATIONS: usize = 10_000;
+
+    /// Returns a random `u64`, shifted right by a random amount so that small operands (which do
+    /// not overflow) are as likely to be tried as large ones.
+    fn random_operand<R: RngCore>(rng: &mut R) -> u64 {
+        rng.next_u64() >> (rng.next_u32() % 64)
+    }
+
+    /// Clamps a `u128` reference result to the range of `u64`, mirroring the saturating
+    /// behaviour of `Slot` and `Epoch`.
+    fn clamp(x: u128) -> u64 {
+        if x > u128::from(u64::max_value()) {
+            u64::max_value()
+        } else {
+            x as u64
+        }
+    }
+
+    macro_rules! fuzz_tests {
+        ($type: ident) => {
+            #[test]
+            fn fuzz_add() {
+                let mut rng = XorShiftRng::from_seed([42; 16]);
+
+                for _ in 0..FUZZ_ITERATIONS {
+                    let (a, b) = (random_operand(&mut rng), random_operand(&mut rng));
+                    let expected = clamp(u128::from(a) + u128::from(b));
+
+                    assert_eq!($type(a) + $type(b), $type(expected), "{} + {}", a, b);
+                    assert_eq!($type(a) + b, $type(expected), "{} + {}", a, b);
+                }
+            }
+
+            #[test]
+            fn fuzz_sub() {
+                let mut rng = XorShiftRng::from_seed([42; 16]);
+
+                for _ in 0..FUZZ_ITERATIONS {
+                    let (a, b) = (random_operand(&mut rng), random_operand(&mut rng));
+                    let expected = clamp((i128::from(a) - i128::from(b)).max(0) as u128);
+
+                    assert_eq!($type(a) - $type(b), $type(expected), "{} - {}", a, b);
+                    assert_eq!($type(a) - b, $type(expected), "{} - {}", a, b);
+                }
+            }
+
+            #[test]
+            fn fuzz_mul() {
+                let mut rng = XorShiftRng::from_seed([42; 16]);
+
+                for _ in 0..FUZZ_ITERATIONS {
+                    let (a, b) = (random_operand(&mut rng), random_operand(&mut rng));
+                    let expected = clamp(u128::from(a) * u128::from(b));
+
+                    assert_eq!($type(a) * $type(b), $type(expected), "{} * {}", a, b);
+                    assert_eq!($type(a) * b, $type(expected), "{} * {}", a, b);
+                }
+            }
+        };
+    }
+
+    macro_rules! sum_tests {
+        ($type: ident) => {
+            #[test]
+            fn sum() {
+                let values = vec![$type(1), $type(2), $type(3)];
+                assert_eq!(values.into_iter().sum::<$type>(), $type(6));
+
+                let empty: Vec<$type> = vec![];
+                assert_eq!(empty.into_iter().sum::<$type>(), $type(0));
+            }
+
+            #[test]
+            fn sum_by_reference() {
+                let values = vec![$type(1), $type(2), $type(3)];
+                assert_eq!(values.iter().sum::<$type>(), $type(6));
+
+                let empty: Vec<$type> = vec![];
+                assert_eq!(empty.iter().sum::<$type>(), $type(0));
+            }
+
+            #[test]
+            fn sum_saturates() {
+                let values = vec![$type(u64::max_value()), $type(1)];
+                assert_eq!(values.iter().sum::<$type>(), $type(u64::max_value()));
+                assert_eq!(values.into_iter().sum::<$type>(), $type(u64::max_value()));
+            }
+        };
+    }
+
+    macro_rules! display_tests {
+        ($type: ident) => {
+            #[test]
+            fn to_radix_string() {
+                let x = $type(3_054);
+
+                assert_eq!(x.to_radix_string(2), Some("101111101110".to_string()));
+                assert_eq!(x.to_radix_string(8), Some("5756".to_string()));
+                assert_eq!(x.to_radix_string(10), Some("3054".to_string()));
+                assert_eq!(x.to_radix_string(16), Some("bee".to_string()));
+                assert_eq!(x.to_radix_string(36), Some("2cu".to_string()));
+            }
+
+            #[test]
+            fn to_radix_string_boundaries() {
+                assert_eq!($type(0).to_radix_string(2), Some("0".to_string()));
+                assert_eq!($type(0).to_radix_string(16), Some("0".to_string()));
+                assert_eq!(
+                    $type(u64::max_value()).to_radix_string(16),
+                    Some("ffffffffffffffff".to_string())
+                );
+                assert_eq!(
+                    $type(u64::max_value()).to_radix_string(10),
+                    Some(u64::max_value().to_string())
+                );
+                // The longest representation.
+                assert_eq!(
+                    $type(u64::max_value()).to_radix_string(2),
+                    Some("1".repeat(64))
+                );
+            }
+
+            #[test]
+            fn hex_round_trip() {
+                for value in &[0, 1, 9, 10, 255, 3_054, u64::max_value()] {
+                    let x = $type(*value);
+                    assert_eq!($type::from_hex(&x.to_hex()), Ok(x));
+                }
+
+                assert_eq!($type(0).to_hex(), "0x0");
+                assert_eq!($type(3_054).to_hex(), "0xbee");
+                assert_eq!($type(u64::max_value()).to_hex(), "0xffffffffffffffff");
+                // `Display` remains decimal.
+                assert_eq!(format!("{}", $type(3_054)), "3054");
+            }
+
+            #[test]
+            fn from_hex() {
+                assert_eq!($type::from_hex("0xbee"), Ok($type(3_054)));
+                assert_eq!($type::from_hex("bee"), Ok($type(3_054)));
+                assert_eq!($type::from_hex("0xBEE"), Ok($type(3_054)));
+                assert_eq!($type::from_hex("0X0"), Ok($type(0)));
+
+                assert_eq!($type::from_hex(""), Err(HexParseError::Empty));
+                assert_eq!($type::from_hex("0x"), Err(HexParseError::Empty));
+                assert_eq!(
+                    $type::from_hex("0xbeeg"),
+                    Err(HexParseError::InvalidDigit('g'))
+                );
+                // A sign is not a hex digit.
+                assert_eq!($type::from_hex("-1"), Err(HexParseError::InvalidDigit('-')));
+                assert_eq!(
+                    $type::from_hex("0x+ff"),
+                    Err(HexParseError::InvalidDigit('+'))
+                );
+                assert_eq!(
+                    $type::from_hex("+ff"),
+                    Err(HexParseError::InvalidDigit('+'))
+                );
+                assert_eq!(
+                    $type::from_hex("0x+"),
+                    Err(HexParseError::InvalidDigit('+'))
+                );
+                // Leading zeros do not overflow...
+                assert_eq!($type::from_hex("0x00000000000000000001"), Ok($type(1)));
+                // ...but values exceeding a `u64` do.
+                assert_eq!(
+                    $type::from_hex("0x10000000000000000"),
+                    Err(HexParseError::Overflow)
+                );
+            }
+
+            #[test]
+            fn to_radix_string_invalid_radix() {
+                assert_eq!($type(1).to_radix_string(0), None);
+                assert_eq!($type(1).to_radix_string(1), None);
+                assert_eq!($type(1).to_radix_string(37), None);
+            }
+        };
+    }
+
     macro_rules! all_tests {
         ($type: ident) => {
             new_tests!($type);
             math_between_tests!($type, $type);
             math_tests!($type);
+            fuzz_tests!($type);
             ssz_tests!($type);
+            serde_tests!($type);
+            num_traits_tests!($type);
+            sum_tests!($type);
+            display_tests!($type);
+
+            #[cfg(feature = "step_trait")]
+            mod step_tests {
+                use super::*;
+
+                step_tests!($type);
+            }
 
             mod u64_tests {
                 use super::*;
@@ -635,12 +1806,81 @@ mod tests {
                     let x = $type(u64::max_value()).as_u64();
                     assert_eq!(x, u64::max_value());
                 }
+
+                #[test]
+                pub fn le_bytes() {
+                    let x = $type(0x0102_0304_0506_0708);
+                    assert_eq!(x.to_le_bytes(), [8, 7, 6, 5, 4, 3, 2, 1]);
+                    assert_eq!($type::from_le_bytes([8, 7, 6, 5, 4, 3, 2, 1]), x);
+
+                    for x in &[0, 1, 256, u64::max_value()] {
+                        let x = $type(*x);
+                        assert_eq!($type::from_le_bytes(x.to_le_bytes()), x);
+                    }
+                }
+
+                #[test]
+                pub fn be_bytes() {
+                    let x = $type(0x0102_0304_0506_0708);
+                    assert_eq!(x.to_be_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+                    assert_eq!($type::from_be_bytes([1, 2, 3, 4, 5, 6, 7, 8]), x);
+
+                    for x in &[0, 1, 256, u64::max_value()] {
+                        let x = $type(*x);
+                        assert_eq!($type::from_be_bytes(x.to_be_bytes()), x);
+                    }
+
+                    // Only palindromic byte arrays are equal in either order.
+                    assert_ne!($type(1).to_le_bytes(), $type(1).to_be_bytes());
+                    assert_eq!(
+                        $type(u64::max_value()).to_le_bytes(),
+                        $type(u64::max_value()).to_be_bytes()
+                    );
+                }
             }
 
             mod usize_tests {
                 use super::*;
 
-                from_into_tests!($type, usize);
+                #[test]
+                fn from() {
+                    assert_eq!($type(0), $type::from(0_usize));
+                    assert_eq!($type(3), $type::from(3_usize));
+                    assert_eq!(
+                        $type(usize::max_value() as u64),
+                        $type::from(usize::max_value())
+                    );
+                }
+
+                #[test]
+                fn try_from() {
+                    assert_eq!(usize::try_from($type(0)), Ok(0));
+                    assert_eq!(usize::try_from($type(3)), Ok(3));
+                    assert_eq!($type(3).try_as_usize(), Ok(3));
+                    assert_eq!(
+                        usize::try_from($type(usize::max_value() as u64)),
+                        Ok(usize::max_value())
+                    );
+                }
+
+                #[test]
+                #[cfg(target_pointer_width = "64")]
+                fn try_from_u64_max() {
+                    assert_eq!(
+                        usize::try_from($type(u64::max_value())),
+                        Ok(usize::max_value())
+                    );
+                }
+
+                #[test]
+                #[cfg(not(target_pointer_width = "64"))]
+                fn try_from_overflow() {
+                    let too_large = $type(usize::max_value() as u64 + 1);
+
+                    assert!(usize::try_from(too_large).is_err());
+                    assert!(too_large.try_as_usize().is_err());
+                    assert!($type(u64::max_value()).try_as_usize().is_err());
+                }
 
                 #[test]
                 pub fn as_usize() {
@@ -657,13 +1897,236 @@ mod tests {
         };
     }
 
+    /// A drain which records the key/value pairs of each record logged to it.
+    struct KVRecordingDrain(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl slog::Serializer for KVRecordingDrain {
+        fn emit_arguments(&mut self, key: slog::Key, val: &fmt::Arguments) -> slog::Result {
+            self.0
+                .lock()
+                .unwrap()
+                .push((key.to_string(), format!("{}", val)));
+            Ok(())
+        }
+    }
+
+    impl slog::Drain for KVRecordingDrain {
+        type Ok = ();
+        type Err = slog::Never;
+
+        fn log(
+            &self,
+            record: &slog::Record,
+            _values: &slog::OwnedKVList,
+        ) -> Result<(), slog::Never> {
+            let mut serializer = KVRecordingDrain(self.0.clone());
+            record.kv().serialize(record, &mut serializer).unwrap();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn slog_kv() {
+        let pairs = Arc::new(Mutex::new(vec![]));
+        let log = slog::Logger::root(KVRecordingDrain(pairs.clone()), slog::o!());
+
+        slog::info!(log, "test"; Slot::new(1234), Epoch::new(12));
+
+        // The order in which pairs are serialized is not specified.
+        let mut pairs = pairs.lock().unwrap().clone();
+        pairs.sort();
+        assert_eq!(
+            pairs,
+            vec![
+                ("epoch".to_string(), "12".to_string()),
+                ("slot".to_string(), "1234".to_string())
+            ]
+        );
+    }
+
     #[cfg(test)]
     mod slot_tests {
         use super::*;
         use crate::test_utils::{SeedableRng, TestRandom, XorShiftRng};
         use ssz::ssz_encode;
+        use std::collections::HashMap;
 
         all_tests!(Slot);
+
+        #[test]
+        fn start_time() {
+            // Genesis at zero.
+            assert_eq!(Slot::new(0).start_time(0, 6), 0);
+            assert_eq!(Slot::new(10).start_time(0, 6), 60);
+            // Non-zero genesis.
+            assert_eq!(Slot::new(0).start_time(1_000, 6), 1_000);
+            assert_eq!(Slot::new(10).start_time(1_000, 6), 1_060);
+            // Saturates.
+            assert_eq!(Slot::max_value().start_time(1_000, 6), u64::max_value());
+        }
+
+        #[test]
+        fn from_timestamp() {
+            // Genesis at zero.
+            assert_eq!(Slot::from_timestamp(0, 0, 6), Slot::new(0));
+            assert_eq!(Slot::from_timestamp(59, 0, 6), Slot::new(9));
+            assert_eq!(Slot::from_timestamp(60, 0, 6), Slot::new(10));
+            // Non-zero genesis.
+            assert_eq!(Slot::from_timestamp(1_000, 1_000, 6), Slot::new(0));
+            assert_eq!(Slot::from_timestamp(1_065, 1_000, 6), Slot::new(10));
+            // Prior to genesis.
+            assert_eq!(Slot::from_timestamp(999, 1_000, 6), Slot::new(0));
+            assert_eq!(Slot::from_timestamp(0, 1_000, 6), Slot::new(0));
+            // Zero slot duration.
+            assert_eq!(Slot::from_timestamp(1_065, 1_000, 0), Slot::new(0));
+        }
+
+        #[test]
+        fn start_time_round_trip() {
+            for slot in &[0, 1, 42, 1_000_000] {
+                let slot = Slot::new(*slot);
+                let ts = slot.start_time(1_554_000_000, 6);
+
+                assert_eq!(Slot::from_timestamp(ts, 1_554_000_000, 6), slot);
+                assert_eq!(Slot::from_timestamp(ts + 5, 1_554_000_000, 6), slot);
+            }
+        }
+
+        #[test]
+        fn is_within_ws_period() {
+            let head = Slot::new(1_000);
+
+            // Well inside the period.
+            assert!(Slot::new(990).is_within_ws_period(head, 100));
+            assert!(head.is_within_ws_period(head, 100));
+            // Exactly at the boundary.
+            assert!(Slot::new(900).is_within_ws_period(head, 100));
+            // Outside the period.
+            assert!(!Slot::new(899).is_within_ws_period(head, 100));
+            assert!(!Slot::new(0).is_within_ws_period(head, 100));
+            // After the head.
+            assert!(Slot::new(1_001).is_within_ws_period(head, 0));
+        }
+
+        #[test]
+        fn position_in_epoch() {
+            let epoch_length = 8;
+
+            let positions: Vec<u64> = (6..18)
+                .map(|slot| Slot::new(slot).position_in_epoch(epoch_length))
+                .collect();
+            assert_eq!(positions, vec![6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1]);
+
+            assert_eq!(Slot::new(13).position_in_epoch(0), 0);
+        }
+
+        #[test]
+        fn epoch_boundaries() {
+            let epoch_length = 8;
+
+            assert!(Slot::new(0).is_epoch_start(epoch_length));
+            assert!(!Slot::new(0).is_epoch_end(epoch_length));
+
+            assert!(!Slot::new(7).is_epoch_start(epoch_length));
+            assert!(Slot::new(7).is_epoch_end(epoch_length));
+
+            assert!(Slot::new(8).is_epoch_start(epoch_length));
+            assert!(!Slot::new(8).is_epoch_end(epoch_length));
+
+            assert!(!Slot::new(9).is_epoch_start(epoch_length));
+            assert!(!Slot::new(9).is_epoch_end(epoch_length));
+
+            // With an epoch length of one, every slot both starts and ends an epoch.
+            assert!(Slot::new(5).is_epoch_start(1));
+            assert!(Slot::new(5).is_epoch_end(1));
+
+            assert!(!Slot::new(0).is_epoch_start(0));
+            assert!(!Slot::new(0).is_epoch_end(0));
+        }
+
+        #[test]
+        fn checked_epoch() {
+            assert_eq!(Slot::new(0).checked_epoch(8), Some(Epoch::new(0)));
+            assert_eq!(Slot::new(7).checked_epoch(8), Some(Epoch::new(0)));
+            assert_eq!(Slot::new(8).checked_epoch(8), Some(Epoch::new(1)));
+            assert_eq!(
+                Slot::new(100).checked_epoch(8),
+                Some(Slot::new(100).epoch(8))
+            );
+
+            assert_eq!(Slot::new(0).checked_epoch(0), None);
+            assert_eq!(Slot::new(100).checked_epoch(0), None);
+        }
+
+        #[test]
+        fn json_hex() {
+            #[derive(Serialize, Deserialize, PartialEq, Debug)]
+            struct Quantities {
+                #[serde(with = "crate::slot_epoch::serde_hex::slot")]
+                hex: Slot,
+                number: Slot,
+            }
+
+            let original = Quantities {
+                hex: Slot::new(3_054),
+                number: Slot::new(3_054),
+            };
+
+            let json = serde_json::to_string(&original).unwrap();
+            assert_eq!(json, r#"{"hex":"0xbee","number":3054}"#);
+
+            let decoded: Quantities = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded, original);
+
+            assert!(serde_json::from_str::<Quantities>(r#"{"hex":3054,"number":3054}"#).is_err());
+            assert!(
+                serde_json::from_str::<Quantities>(r#"{"hex":"0xbeeg","number":3054}"#).is_err()
+            );
+        }
+
+        #[test]
+        fn slots_since() {
+            assert_eq!(Slot::new(10).slots_since(Slot::new(3)), 7);
+            assert_eq!(Slot::new(10).slots_since(Slot::new(10)), 0);
+            assert_eq!(Slot::new(3).slots_since(Slot::new(10)), 0);
+            assert_eq!(
+                Slot::new(u64::max_value()).slots_since(Slot::new(0)),
+                u64::max_value()
+            );
+        }
+
+        #[test]
+        fn duration_since_genesis() {
+            assert_eq!(
+                Slot::new(0).duration_since_genesis(6),
+                Duration::from_secs(0)
+            );
+            assert_eq!(
+                Slot::new(1).duration_since_genesis(6),
+                Duration::from_secs(6)
+            );
+            assert_eq!(
+                Slot::new(10).duration_since_genesis(6),
+                Duration::from_secs(60)
+            );
+            assert_eq!(
+                Slot::new(u64::max_value() / 6 + 1).duration_since_genesis(6),
+                max_duration()
+            );
+        }
+
+        #[test]
+        fn is_in_epoch() {
+            let epoch_length = 8;
+            let epoch = Epoch::new(2);
+
+            assert!(!Slot::new(15).is_in_epoch(epoch, epoch_length));
+            assert!(Slot::new(16).is_in_epoch(epoch, epoch_length));
+            assert!(Slot::new(23).is_in_epoch(epoch, epoch_length));
+            assert!(!Slot::new(24).is_in_epoch(epoch, epoch_length));
+
+            assert!(!Slot::new(0).is_in_epoch(Epoch::new(0), 0));
+        }
     }
 
     #[cfg(test)]
@@ -671,7 +2134,359 @@ mod tests {
         use super::*;
         use crate::test_utils::{SeedableRng, TestRandom, XorShiftRng};
         use ssz::ssz_encode;
+        use std::collections::HashMap;
 
         all_tests!(Epoch);
+
+        #[test]
+        fn checked_start_slot() {
+            assert_eq!(Epoch::new(0).checked_start_slot(8), Some(Slot::new(0)));
+            assert_eq!(Epoch::new(3).checked_start_slot(8), Some(Slot::new(24)));
+            assert_eq!(
+                Epoch::new(3).checked_start_slot(8),
+                Some(Epoch::new(3).start_slot(8))
+            );
+
+            assert_eq!(Epoch::new(0).checked_start_slot(0), None);
+            assert_eq!(Epoch::new(3).checked_start_slot(0), None);
+            assert_eq!(Epoch::new(u64::max_value()).checked_start_slot(2), None);
+        }
+
+        #[test]
+        fn start_slot_checked() {
+            assert_eq!(Epoch::new(3).start_slot_checked(8), Ok(Slot::new(24)));
+            assert_eq!(
+                Epoch::new(3).start_slot_checked(0),
+                Err(SlotError::EpochLengthIsZero)
+            );
+
+            let last_epoch = Epoch::new(u64::max_value() / 8);
+            assert_eq!(
+                last_epoch.start_slot_checked(8),
+                Ok(last_epoch.start_slot(8))
+            );
+            assert_eq!(
+                (last_epoch + 1).start_slot_checked(8),
+                Err(SlotError::Overflow {
+                    epoch: last_epoch + 1,
+                    epoch_length: 8
+                })
+            );
+        }
+
+        #[test]
+        fn end_slot_checked() {
+            assert_eq!(Epoch::new(3).end_slot_checked(8), Ok(Slot::new(31)));
+            assert_eq!(
+                Epoch::new(3).end_slot_checked(0),
+                Err(SlotError::EpochLengthIsZero)
+            );
+
+            // The last slot of the last epoch is `u64::max_value()`.
+            let last_epoch = Epoch::new(u64::max_value() / 8);
+            assert_eq!(
+                last_epoch.end_slot_checked(8),
+                Ok(Slot::new(u64::max_value()))
+            );
+            assert_eq!(
+                (last_epoch + 1).end_slot_checked(8),
+                Err(SlotError::Overflow {
+                    epoch: last_epoch + 1,
+                    epoch_length: 8
+                })
+            );
+
+            // The epoch starts within range, but ends beyond it.
+            let epoch = Epoch::new(u64::max_value() / 10);
+            assert!(epoch.start_slot_checked(10).is_ok());
+            assert_eq!(
+                epoch.end_slot_checked(10),
+                Err(SlotError::Overflow {
+                    epoch,
+                    epoch_length: 10
+                })
+            );
+        }
+
+        #[test]
+        fn slot_iter_forward() {
+            let epoch_length = 8;
+            let epoch = Epoch::new(3);
+
+            let slots: Vec<Slot> = epoch.slot_iter(epoch_length).collect();
+            let expected: Vec<Slot> = (24..32).map(Slot::new).collect();
+
+            assert_eq!(slots, expected);
+            assert_eq!(slots[0], epoch.start_slot(epoch_length));
+            assert_eq!(slots[7], epoch.end_slot(epoch_length));
+        }
+
+        #[test]
+        fn slot_iter_backward() {
+            let epoch_length = 8;
+            let epoch = Epoch::new(3);
+
+            let slots: Vec<Slot> = epoch.slot_iter(epoch_length).rev().collect();
+            let expected: Vec<Slot> = (24..32).rev().map(Slot::new).collect();
+
+            assert_eq!(slots, expected);
+        }
+
+        #[test]
+        fn slot_iter_both_ends() {
+            let epoch_length = 5;
+            let mut iter = Epoch::new(1).slot_iter(epoch_length);
+
+            assert_eq!(iter.len(), 5);
+            assert_eq!(iter.next(), Some(Slot::new(5)));
+            assert_eq!(iter.next_back(), Some(Slot::new(9)));
+            assert_eq!(iter.next(), Some(Slot::new(6)));
+            assert_eq!(iter.next_back(), Some(Slot::new(8)));
+            assert_eq!(iter.len(), 1);
+            // The ends meet in the middle, the last slot is yielded only once.
+            assert_eq!(iter.next_back(), Some(Slot::new(7)));
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn json_hex() {
+            #[derive(Serialize, Deserialize, PartialEq, Debug)]
+            struct Quantities {
+                #[serde(with = "crate::slot_epoch::serde_hex::epoch")]
+                hex: Epoch,
+                number: Epoch,
+            }
+
+            let original = Quantities {
+                hex: Epoch::new(3_054),
+                number: Epoch::new(3_054),
+            };
+
+            let json = serde_json::to_string(&original).unwrap();
+            assert_eq!(json, r#"{"hex":"0xbee","number":3054}"#);
+
+            let decoded: Quantities = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded, original);
+
+            assert!(serde_json::from_str::<Quantities>(r#"{"hex":3054,"number":3054}"#).is_err());
+            assert!(
+                serde_json::from_str::<Quantities>(r#"{"hex":"0xbeeg","number":3054}"#).is_err()
+            );
+        }
+
+        #[test]
+        fn epochs_since() {
+            assert_eq!(Epoch::new(10).epochs_since(Epoch::new(3)), 7);
+            assert_eq!(Epoch::new(10).epochs_since(Epoch::new(10)), 0);
+            assert_eq!(Epoch::new(3).epochs_since(Epoch::new(10)), 0);
+            assert_eq!(
+                Epoch::new(u64::max_value()).epochs_since(Epoch::new(0)),
+                u64::max_value()
+            );
+        }
+
+        #[test]
+        fn duration_since_genesis() {
+            assert_eq!(
+                Epoch::new(0).duration_since_genesis(6, 8),
+                Duration::from_secs(0)
+            );
+            assert_eq!(
+                Epoch::new(1).duration_since_genesis(6, 8),
+                Duration::from_secs(48)
+            );
+            assert_eq!(
+                Epoch::new(3).duration_since_genesis(6, 8),
+                Slot::new(24).duration_since_genesis(6)
+            );
+            // The slot overflows.
+            assert_eq!(
+                Epoch::new(u64::max_value() / 8 + 1).duration_since_genesis(1, 8),
+                max_duration()
+            );
+            // The slot does not overflow, but its seconds do.
+            assert_eq!(
+                Epoch::new(u64::max_value() / 8).duration_since_genesis(6, 8),
+                max_duration()
+            );
+        }
+
+        #[test]
+        fn contains_slot() {
+            let epoch_length = 8;
+            let epoch = Epoch::new(2);
+
+            assert!(!epoch.contains_slot(epoch.start_slot(epoch_length) - 1, epoch_length));
+            assert!(epoch.contains_slot(epoch.start_slot(epoch_length), epoch_length));
+            assert!(epoch.contains_slot(epoch.end_slot(epoch_length), epoch_length));
+            assert!(!epoch.contains_slot(epoch.end_slot(epoch_length) + 1, epoch_length));
+
+            assert!(!Epoch::new(0).contains_slot(Slot::new(0), 0));
+        }
+
+        #[test]
+        fn slot_iter_through() {
+            let epoch_length = 8;
+
+            let slots: Vec<Slot> = Epoch::new(3)
+                .slot_iter_through(Epoch::new(4), epoch_length)
+                .collect();
+            let expected: Vec<Slot> = (24..40).map(Slot::new).collect();
+            assert_eq!(slots, expected);
+
+            // A single epoch is equivalent to `slot_iter`.
+            assert!(Epoch::new(3)
+                .slot_iter_through(Epoch::new(3), epoch_length)
+                .eq(Epoch::new(3).slot_iter(epoch_length)));
+
+            // Zero epochs.
+            assert_eq!(
+                Epoch::new(3)
+                    .slot_iter_through(Epoch::new(2), epoch_length)
+                    .count(),
+                0
+            );
+        }
+
+        #[test]
+        fn iter_to() {
+            let epochs: Vec<Epoch> = Epoch::new(2).iter_to(Epoch::new(6)).collect();
+            let expected: Vec<Epoch> = (2..6).map(Epoch::new).collect();
+
+            assert_eq!(epochs, expected);
+        }
+
+        #[test]
+        fn iter_to_empty() {
+            assert_eq!(Epoch::new(4).iter_to(Epoch::new(4)).count(), 0);
+            assert_eq!(Epoch::new(4).iter_to(Epoch::new(1)).len(), 0);
+            assert_eq!(Epoch::new(4).iter_to(Epoch::new(1)).next(), None);
+        }
+
+        #[test]
+        fn iter_with_bounds() {
+            let epoch_length = 8;
+            let bounds: Vec<(Epoch, Slot, Slot)> = Epoch::new(2)
+                .iter_with_bounds(Epoch::new(5), epoch_length)
+                .collect();
+
+            // The range is half-open.
+            assert_eq!(
+                bounds
+                    .iter()
+                    .map(|(epoch, _, _)| *epoch)
+                    .collect::<Vec<_>>(),
+                vec![Epoch::new(2), Epoch::new(3), Epoch::new(4)]
+            );
+            for (epoch, start_slot, end_slot) in bounds {
+                assert_eq!(start_slot, epoch.start_slot(epoch_length));
+                assert_eq!(end_slot, epoch.end_slot(epoch_length));
+            }
+            assert_eq!(
+                Epoch::new(2)
+                    .iter_with_bounds(Epoch::new(3), epoch_length)
+                    .next(),
+                Some((Epoch::new(2), Slot::new(16), Slot::new(23)))
+            );
+        }
+
+        #[test]
+        fn iter_with_bounds_empty() {
+            assert_eq!(Epoch::new(4).iter_with_bounds(Epoch::new(4), 8).len(), 0);
+            assert_eq!(
+                Epoch::new(4).iter_with_bounds(Epoch::new(1), 8).next(),
+                None
+            );
+        }
+
+        #[test]
+        fn iter_to_single() {
+            let mut iter = Epoch::new(4).iter_to(Epoch::new(5));
+
+            assert_eq!(iter.len(), 1);
+            assert_eq!(iter.next(), Some(Epoch::new(4)));
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn iter_to_large_range() {
+            let end = Epoch::new(u64::max_value());
+            let start = end - 1_000_000;
+            let mut iter = start.iter_to(end);
+
+            assert_eq!(iter.len(), 1_000_000);
+            assert_eq!(iter.next(), Some(start));
+            assert_eq!(iter.last(), Some(end - 1));
+        }
+
+        #[test]
+        fn slot_iter_len() {
+            let epoch_length = 64;
+            let mut iter = Epoch::new(2).slot_iter(epoch_length);
+
+            assert_eq!(iter.len(), 64);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.len(), 62);
+            assert_eq!(iter.count(), 62);
+        }
+
+        #[test]
+        fn from_slot() {
+            assert_eq!(Epoch::from_slot(Slot::new(0), 8), Epoch::new(0));
+            assert_eq!(Epoch::from_slot(Slot::new(7), 8), Epoch::new(0));
+            assert_eq!(Epoch::from_slot(Slot::new(8), 8), Epoch::new(1));
+            assert_eq!(Epoch::from_slot(Slot::new(17), 8), Slot::new(17).epoch(8));
+        }
+    }
+
+    mod slot_epoch_context_tests {
+        use super::*;
+
+        #[test]
+        fn new() {
+            assert_eq!(
+                SlotEpochContext::new(64),
+                SlotEpochContext { epoch_length: 64 }
+            );
+        }
+
+        #[test]
+        fn epoch_of() {
+            let context = SlotEpochContext::new(8);
+
+            assert_eq!(context.epoch_of(Slot::new(0)), Epoch::new(0));
+            assert_eq!(context.epoch_of(Slot::new(7)), Epoch::new(0));
+            assert_eq!(context.epoch_of(Slot::new(8)), Epoch::new(1));
+            assert_eq!(context.epoch_of(Slot::new(30)), Epoch::new(3));
+        }
+
+        #[test]
+        fn start_slot_of() {
+            let context = SlotEpochContext::new(8);
+
+            assert_eq!(context.start_slot_of(Epoch::new(0)), Slot::new(0));
+            assert_eq!(context.start_slot_of(Epoch::new(3)), Slot::new(24));
+            assert_eq!(
+                context.start_slot_of(context.epoch_of(Slot::new(30))),
+                Slot::new(24)
+            );
+        }
+
+        #[test]
+        fn slots_of() {
+            let context = SlotEpochContext::new(4);
+            let slots: Vec<Slot> = context.slots_of(Epoch::new(2)).collect();
+
+            assert_eq!(
+                slots,
+                vec![Slot::new(8), Slot::new(9), Slot::new(10), Slot::new(11)]
+            );
+            assert_eq!(context.slots_of(Epoch::new(2)).len(), 4);
+            assert_eq!(SlotEpochContext::new(0).slots_of(Epoch::new(2)).len(), 0);
+        }
     }
 }
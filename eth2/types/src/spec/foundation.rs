@@ -1,7 +1,7 @@
 use super::ChainSpec;
 use bls::{Keypair, PublicKey, SecretKey, Signature};
 
-use crate::{Address, Eth1Data, Hash256, Slot, Validator};
+use crate::{Address, Epoch, Eth1Data, Hash256, Slot, Validator};
 
 /// The size of a validators deposit in GWei.
 pub const DEPOSIT_GWEI: u64 = 32_000_000_000;
@@ -43,6 +43,11 @@ impl ChainSpec {
             zero_hash: Hash256::zero(),
             empty_signature: Signature::empty_signature(),
             bls_withdrawal_prefix_byte: 0x00,
+            /*
+             * Fork schedule
+             */
+            fork_version: 0,
+            fork_epoch: Epoch::from(u64::max_value()),
             /*
              * Time parameters
              */
@@ -68,6 +73,12 @@ impl ChainSpec {
             max_attestations: 128,
             max_deposits: 16,
             max_exits: 16,
+            /*
+             * Signature domains
+             */
+            domain_proposal: 2,
+            domain_randao: 4,
+            domain_sync_committee: 7,
             /*
              * Intialization parameters
              */
@@ -1,6 +1,6 @@
 mod foundation;
 
-use crate::{Address, Eth1Data, Hash256, Slot, Validator};
+use crate::{Address, Epoch, Eth1Data, Fork, Hash256, Slot, Validator};
 use bls::Signature;
 
 #[derive(PartialEq, Debug, Clone)]
@@ -35,6 +35,11 @@ pub struct ChainSpec {
     pub zero_hash: Hash256,
     pub empty_signature: Signature,
     pub bls_withdrawal_prefix_byte: u8,
+    /*
+     * Fork schedule
+     */
+    pub fork_version: u64,
+    pub fork_epoch: Epoch,
     /*
      * Time parameters
      */
@@ -60,6 +65,12 @@ pub struct ChainSpec {
     pub max_attestations: u64,
     pub max_deposits: u64,
     pub max_exits: u64,
+    /*
+     * Signature domains
+     */
+    pub domain_proposal: u64,
+    pub domain_randao: u64,
+    pub domain_sync_committee: u64,
     /*
      * Intialization parameters
      */
@@ -68,3 +79,87 @@ pub struct ChainSpec {
     pub genesis_time: u64,
     pub intial_eth1_data: Eth1Data,
 }
+
+impl ChainSpec {
+    /// Returns the number of committees per slot, given the number of active validators.
+    ///
+    /// Bounded by `shard_count / epoch_length`, with a minimum of one.
+    pub fn committees_per_slot(&self, active_validator_count: u64) -> u64 {
+        std::cmp::max(
+            1,
+            std::cmp::min(
+                self.shard_count / self.epoch_length,
+                active_validator_count / self.epoch_length / self.target_committee_size,
+            ),
+        )
+    }
+
+    /// Returns the number of committees per epoch, given the number of active validators.
+    ///
+    /// Bounded by `shard_count`, with a minimum of one committee per slot.
+    pub fn committees_per_epoch(&self, active_validator_count: u64) -> u64 {
+        self.committees_per_slot(active_validator_count) * self.epoch_length
+    }
+
+    /// Returns the `Fork` described by this spec.
+    ///
+    /// The fork version switches from `genesis_fork_version` to `fork_version` at the first slot
+    /// of `fork_epoch`.
+    pub fn fork(&self) -> Fork {
+        Fork {
+            pre_fork_version: self.genesis_fork_version,
+            post_fork_version: self.fork_version,
+            fork_slot: self.fork_epoch.start_slot(self.epoch_length),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn committees_per_epoch_minimum() {
+        let spec = ChainSpec::foundation();
+
+        assert_eq!(spec.committees_per_slot(0), 1);
+        assert_eq!(spec.committees_per_epoch(0), spec.epoch_length);
+        assert_eq!(spec.committees_per_epoch(4), spec.epoch_length);
+
+        // Just short of enough validators for two committees per slot.
+        let count = 2 * spec.epoch_length * spec.target_committee_size - 1;
+        assert_eq!(spec.committees_per_epoch(count), spec.epoch_length);
+        assert_eq!(spec.committees_per_epoch(count + 1), 2 * spec.epoch_length);
+    }
+
+    #[test]
+    fn fork() {
+        let mut spec = ChainSpec::foundation();
+        spec.fork_version = spec.genesis_fork_version + 1;
+        spec.fork_epoch = Epoch::new(2);
+
+        let fork = spec.fork();
+        assert_eq!(fork.fork_slot, Slot::new(2 * spec.epoch_length));
+        assert_eq!(
+            fork.get_fork_version(fork.fork_slot - 1),
+            spec.genesis_fork_version
+        );
+        assert_eq!(fork.get_fork_version(fork.fork_slot), spec.fork_version);
+    }
+
+    #[test]
+    fn committees_per_epoch_shard_bound() {
+        let spec = ChainSpec::foundation();
+        let max_per_slot = spec.shard_count / spec.epoch_length;
+
+        let count = max_per_slot * spec.epoch_length * spec.target_committee_size;
+        assert_eq!(spec.committees_per_slot(count), max_per_slot);
+        assert_eq!(spec.committees_per_epoch(count), spec.shard_count);
+
+        assert_eq!(spec.committees_per_epoch(count * 10), spec.shard_count);
+        assert_eq!(
+            spec.committees_per_epoch(u64::max_value()),
+            spec.shard_count
+        );
+    }
+}
@@ -0,0 +1,14 @@
+/// Note: sync committees do not exist in the present spec.
+///
+/// A member of a sync committee signs the head block root at each slot to allow light clients to
+/// follow the chain.
+use super::{Hash256, Signature, Slot};
+use serde_derive::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SyncCommitteeMessage {
+    pub slot: Slot,
+    pub beacon_block_root: Hash256,
+    pub validator_index: u64,
+    pub signature: Signature,
+}
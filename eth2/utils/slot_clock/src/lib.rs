@@ -3,10 +3,53 @@ mod testing_slot_clock;
 
 pub use crate::system_time_slot_clock::{Error as SystemTimeSlotClockError, SystemTimeSlotClock};
 pub use crate::testing_slot_clock::{Error as TestingSlotClockError, TestingSlotClock};
+use std::thread;
+use std::time::Duration;
 pub use types::Slot;
 
 pub trait SlotClock: Send + Sync {
     type Error;
 
     fn present_slot(&self) -> Result<Option<Slot>, Self::Error>;
+
+    /// As per `present_slot`, but a clock whose internal state has been poisoned (i.e., another
+    /// thread panicked whilst holding a lock on it) recovers that state, rather than returning an
+    /// error.
+    fn present_slot_recovering(&self) -> Result<Option<Slot>, Self::Error> {
+        self.present_slot()
+    }
+
+    /// Returns `true` if the `error` indicates that the clock's internal state has been poisoned.
+    fn is_poisoned(_error: &Self::Error) -> bool {
+        false
+    }
+
+    /// Returns the duration until the start of the next slot (or until genesis, if genesis has not
+    /// yet occurred).
+    fn duration_to_next_slot(&self) -> Result<Duration, Self::Error>;
+
+    /// Returns the duration since the start of the present slot, or `None` if genesis has not yet
+    /// occurred.
+    fn present_duration_into_slot(&self) -> Result<Option<Duration>, Self::Error>;
+
+    /// Blocks the thread until the present slot is `target` (or later).
+    ///
+    /// Sleeps until the start of each subsequent slot, checking the present slot each time.
+    fn sleep_until_slot(&self, target: Slot) -> Result<(), Self::Error> {
+        loop {
+            if let Some(slot) = self.present_slot()? {
+                if slot >= target {
+                    return Ok(());
+                }
+            }
+
+            thread::sleep(self.duration_to_next_slot()?);
+        }
+    }
+
+    /// Returns the number of slots per epoch assumed by the clock, if the clock has any concept of
+    /// epochs.
+    fn epoch_length(&self) -> Option<u64> {
+        None
+    }
 }
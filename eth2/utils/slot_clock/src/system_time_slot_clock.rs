@@ -49,6 +49,21 @@ impl SlotClock for SystemTimeSlotClock {
             Some(d) => Ok(slot_from_duration(self.slot_duration_seconds, d)),
         }
     }
+
+    fn duration_to_next_slot(&self) -> Result<Duration, Error> {
+        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+        duration_to_next_slot(self.genesis_seconds, self.slot_duration_seconds, now)
+            .ok_or(Error::SlotDurationIsZero)
+    }
+
+    fn present_duration_into_slot(&self) -> Result<Option<Duration>, Error> {
+        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+        Ok(duration_into_slot(
+            self.genesis_seconds,
+            self.slot_duration_seconds,
+            now,
+        ))
+    }
 }
 
 impl From<SystemTimeError> for Error {
@@ -63,6 +78,49 @@ fn slot_from_duration(slot_duration_seconds: u64, duration: Duration) -> Option<
     ))
 }
 
+/// Returns the duration from `now` (since the UNIX epoch) until the start of the next slot, or
+/// until genesis if `now` is prior to genesis.
+///
+/// Returns `None` if `slot_duration_seconds == 0`.
+fn duration_to_next_slot(
+    genesis_seconds: u64,
+    slot_duration_seconds: u64,
+    now: Duration,
+) -> Option<Duration> {
+    let genesis = Duration::from_secs(genesis_seconds);
+    let slot_duration = Duration::from_secs(slot_duration_seconds).as_nanos();
+
+    if slot_duration == 0 {
+        None
+    } else if now < genesis {
+        Some(genesis - now)
+    } else {
+        let into_slot = (now - genesis).as_nanos() % slot_duration;
+        Some(Duration::from_nanos((slot_duration - into_slot) as u64))
+    }
+}
+
+/// Returns the duration from the start of the slot containing `now` (since the UNIX epoch) until
+/// `now`.
+///
+/// Returns `None` if `now` is prior to genesis or `slot_duration_seconds == 0`.
+fn duration_into_slot(
+    genesis_seconds: u64,
+    slot_duration_seconds: u64,
+    now: Duration,
+) -> Option<Duration> {
+    let since_genesis = now.checked_sub(Duration::from_secs(genesis_seconds))?;
+    let slot_duration = Duration::from_secs(slot_duration_seconds).as_nanos();
+
+    if slot_duration == 0 {
+        None
+    } else {
+        Some(Duration::from_nanos(
+            (since_genesis.as_nanos() % slot_duration) as u64,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +183,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_to_next_slot() {
+        let genesis = 1_000;
+        let slot_time = 10;
+        let at = |secs: u64, millis: u64| Duration::from_secs(secs) + Duration::from_millis(millis);
+
+        // Before genesis.
+        assert_eq!(
+            duration_to_next_slot(genesis, slot_time, at(990, 0)),
+            Some(at(10, 0))
+        );
+        assert_eq!(
+            duration_to_next_slot(genesis, slot_time, at(999, 500)),
+            Some(at(0, 500))
+        );
+        // At the start of a slot.
+        assert_eq!(
+            duration_to_next_slot(genesis, slot_time, at(1_000, 0)),
+            Some(at(10, 0))
+        );
+        // Part way through a slot.
+        assert_eq!(
+            duration_to_next_slot(genesis, slot_time, at(1_023, 250)),
+            Some(at(6, 750))
+        );
+
+        assert_eq!(duration_to_next_slot(genesis, 0, at(1_023, 0)), None);
+    }
+
+    #[test]
+    fn test_duration_into_slot() {
+        let genesis = 1_000;
+        let slot_time = 10;
+        let at = |secs: u64, millis: u64| Duration::from_secs(secs) + Duration::from_millis(millis);
+
+        // Before genesis.
+        assert_eq!(duration_into_slot(genesis, slot_time, at(999, 500)), None);
+        // At the start of a slot.
+        assert_eq!(
+            duration_into_slot(genesis, slot_time, at(1_000, 0)),
+            Some(at(0, 0))
+        );
+        // Part way through a slot.
+        assert_eq!(
+            duration_into_slot(genesis, slot_time, at(1_023, 250)),
+            Some(at(3, 250))
+        );
+
+        assert_eq!(duration_into_slot(genesis, 0, at(1_023, 0)), None);
+    }
+
     #[test]
     fn test_slot_from_duration_slot_time_zero() {
         let slot_time = 0;
@@ -1,13 +1,23 @@
 use super::SlotClock;
-use std::sync::RwLock;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{PoisonError, RwLock};
+use std::time::Duration;
 use types::Slot;
 
+/// The duration reported until the next slot, as a `TestingSlotClock` only changes slot when it
+/// is set manually.
+const POLL_INTERVAL_MILLIS: u64 = 1;
+
 #[derive(Debug, PartialEq)]
-pub enum Error {}
+pub enum Error {
+    Poisoned,
+}
 
 /// Determines the present slot based upon the present system time.
 pub struct TestingSlotClock {
     slot: RwLock<u64>,
+    duration_into_slot: RwLock<Duration>,
+    epoch_length: RwLock<Option<u64>>,
 }
 
 impl TestingSlotClock {
@@ -17,26 +27,98 @@ impl TestingSlotClock {
     pub fn new(slot: u64) -> TestingSlotClock {
         TestingSlotClock {
             slot: RwLock::new(slot),
+            duration_into_slot: RwLock::new(Duration::from_secs(0)),
+            epoch_length: RwLock::new(None),
         }
     }
 
     pub fn set_slot(&self, slot: u64) {
         *self.slot.write().expect("TestingSlotClock poisoned.") = slot;
     }
+
+    /// Set the duration reported by `present_duration_into_slot`, i.e., how far through the
+    /// present slot the clock is.
+    pub fn set_duration_into_slot(&self, duration: Duration) {
+        *self
+            .duration_into_slot
+            .write()
+            .expect("TestingSlotClock poisoned.") = duration;
+    }
+
+    /// Panics with a descriptive message if the present slot is not `expected`.
+    pub fn assert_slot(&self, expected: Slot) {
+        let slot = Slot::new(*self.slot.read().expect("TestingSlotClock poisoned."));
+        assert!(
+            slot == expected,
+            "TestingSlotClock: expected slot {}, but the present slot is {}",
+            expected,
+            slot
+        );
+    }
+
+    /// Poisons the clock, as if another thread had panicked whilst setting the slot.
+    ///
+    /// Subsequent calls to `present_slot` return `Error::Poisoned`.
+    pub fn poison(&self) {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _slot = self.slot.write();
+            panic!("Poisoning TestingSlotClock");
+        }));
+    }
+
+    /// Set the epoch length reported by `epoch_length`.
+    pub fn set_epoch_length(&self, epoch_length: u64) {
+        *self
+            .epoch_length
+            .write()
+            .expect("TestingSlotClock poisoned.") = Some(epoch_length);
+    }
 }
 
 impl SlotClock for TestingSlotClock {
     type Error = Error;
 
     fn present_slot(&self) -> Result<Option<Slot>, Error> {
-        let slot = *self.slot.read().expect("TestingSlotClock poisoned.");
+        let slot = *self.slot.read().map_err(|_| Error::Poisoned)?;
         Ok(Some(Slot::new(slot)))
     }
+
+    fn present_slot_recovering(&self) -> Result<Option<Slot>, Error> {
+        let slot = *self.slot.read().unwrap_or_else(PoisonError::into_inner);
+        Ok(Some(Slot::new(slot)))
+    }
+
+    fn is_poisoned(error: &Error) -> bool {
+        *error == Error::Poisoned
+    }
+
+    /// The slot only changes when it is set manually, so this always returns a short interval
+    /// at which the clock may be polled.
+    fn duration_to_next_slot(&self) -> Result<Duration, Error> {
+        Ok(Duration::from_millis(POLL_INTERVAL_MILLIS))
+    }
+
+    fn present_duration_into_slot(&self) -> Result<Option<Duration>, Error> {
+        let duration = *self
+            .duration_into_slot
+            .read()
+            .expect("TestingSlotClock poisoned.");
+        Ok(Some(duration))
+    }
+
+    fn epoch_length(&self) -> Option<u64> {
+        *self
+            .epoch_length
+            .read()
+            .expect("TestingSlotClock poisoned.")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+    use std::thread;
 
     #[test]
     fn test_slot_now() {
@@ -45,4 +127,74 @@ mod tests {
         clock.set_slot(123);
         assert_eq!(clock.present_slot(), Ok(Some(Slot::new(123))));
     }
+
+    #[test]
+    fn test_sleep_until_slot() {
+        let clock = Arc::new(TestingSlotClock::new(10));
+
+        // Returns immediately if the target slot has already been reached.
+        clock.sleep_until_slot(Slot::new(9)).unwrap();
+        clock.sleep_until_slot(Slot::new(10)).unwrap();
+
+        let setter = {
+            let clock = clock.clone();
+            thread::spawn(move || {
+                for slot in 11..=15 {
+                    thread::sleep(Duration::from_millis(5));
+                    clock.set_slot(slot);
+                }
+            })
+        };
+
+        clock.sleep_until_slot(Slot::new(13)).unwrap();
+        assert!(clock.present_slot().unwrap().unwrap() >= Slot::new(13));
+
+        setter.join().unwrap();
+        clock.assert_slot(Slot::new(15));
+    }
+
+    #[test]
+    fn test_assert_slot() {
+        let clock = TestingSlotClock::new(10);
+        clock.assert_slot(Slot::new(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "TestingSlotClock: expected slot 11, but the present slot is 10")]
+    fn test_assert_slot_mismatch() {
+        let clock = TestingSlotClock::new(10);
+        clock.assert_slot(Slot::new(11));
+    }
+
+    #[test]
+    fn test_duration_into_slot() {
+        let clock = TestingSlotClock::new(10);
+        assert_eq!(
+            clock.present_duration_into_slot(),
+            Ok(Some(Duration::from_secs(0)))
+        );
+        clock.set_duration_into_slot(Duration::from_millis(1_500));
+        assert_eq!(
+            clock.present_duration_into_slot(),
+            Ok(Some(Duration::from_millis(1_500)))
+        );
+    }
+
+    #[test]
+    fn test_poison() {
+        let clock = TestingSlotClock::new(10);
+        clock.poison();
+
+        let error = clock.present_slot().unwrap_err();
+        assert!(TestingSlotClock::is_poisoned(&error));
+        assert_eq!(clock.present_slot_recovering(), Ok(Some(Slot::new(10))));
+    }
+
+    #[test]
+    fn test_epoch_length() {
+        let clock = TestingSlotClock::new(10);
+        assert_eq!(clock.epoch_length(), None);
+        clock.set_epoch_length(64);
+        assert_eq!(clock.epoch_length(), Some(64));
+    }
 }
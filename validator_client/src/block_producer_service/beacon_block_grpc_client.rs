@@ -1,11 +1,18 @@
-use block_producer::{BeaconNode, BeaconNodeError, PublishOutcome};
+use block_producer::{
+    AttestationPublishOutcome, BeaconNode, BeaconNodeError, NoBlockReason, NodeHealth,
+    ProduceOutcome, PublishOutcome, ValidatorStatus,
+};
+use grpcio::{Error as GrpcError, RpcStatusCode};
 use protos::services::{
     BeaconBlock as GrpcBeaconBlock, ProduceBeaconBlockRequest, PublishBeaconBlockRequest,
 };
 use protos::services_grpc::BeaconBlockServiceClient;
 use ssz::{ssz_encode, Decodable};
 use std::sync::Arc;
-use types::{BeaconBlock, BeaconBlockBody, Eth1Data, Hash256, PublicKey, Signature, Slot};
+use types::{
+    AttestationData, BeaconBlock, BeaconBlockBody, Eth1Data, FreeAttestation, Hash256, PublicKey,
+    Signature, Slot,
+};
 
 /// A newtype designed to wrap the gRPC-generated service so the `BeaconNode` trait may be
 /// implemented upon it.
@@ -20,28 +27,73 @@ impl BeaconBlockGrpcClient {
 }
 
 impl BeaconNode for BeaconBlockGrpcClient {
-    fn proposer_nonce(&self, pubkey: &PublicKey) -> Result<u64, BeaconNodeError> {
+    /// Request the proposer nonce of a validator from the Beacon Node (BN).
+    ///
+    /// Presently the gRPC API does not expose the proposer nonce, so this always fails.
+    fn proposer_nonce(&self, _pubkey: &PublicKey) -> Result<u64, BeaconNodeError> {
         // TODO: this might not be required.
         //
         // See: https://github.com/ethereum/eth2.0-specs/pull/496
-        panic!("Not implemented.")
+        Err(BeaconNodeError::RemoteFailure(
+            "Proposer nonce is not supported.".to_string(),
+        ))
+    }
+
+    /// Request the number of peers connected to the Beacon Node (BN).
+    ///
+    /// Presently the gRPC API does not expose peer information, so this always fails.
+    fn peer_count(&self) -> Result<u32, BeaconNodeError> {
+        // TODO: add a peer count endpoint to the gRPC API.
+        Err(BeaconNodeError::RemoteFailure(
+            "Peer count is not supported.".to_string(),
+        ))
+    }
+
+    /// Request the epoch length used by the Beacon Node (BN).
+    ///
+    /// Presently the gRPC API does not expose the node's spec, so the epoch length is never
+    /// reported.
+    fn epoch_length(&self) -> Result<Option<u64>, BeaconNodeError> {
+        // TODO: add a spec endpoint to the gRPC API.
+        Ok(None)
     }
+
+    /// Request the activation status of a validator from the Beacon Node (BN).
+    ///
+    /// Presently the gRPC API does not expose validator status, so this always fails.
+    fn validator_status(&self, _pubkey: &PublicKey) -> Result<ValidatorStatus, BeaconNodeError> {
+        // TODO: add a validator status endpoint to the gRPC API.
+        Err(BeaconNodeError::RemoteFailure(
+            "Validator status is not supported.".to_string(),
+        ))
+    }
+
+    /// Request the sync status and head of the Beacon Node (BN).
+    ///
+    /// Presently the gRPC API does not expose node health, so this always fails.
+    fn health(&self) -> Result<NodeHealth, BeaconNodeError> {
+        // TODO: add a health endpoint to the gRPC API.
+        Err(BeaconNodeError::RemoteFailure(
+            "Node health is not supported.".to_string(),
+        ))
+    }
+
     /// Request a Beacon Node (BN) to produce a new block at the supplied slot.
     ///
-    /// Returns `None` if it is not possible to produce at the supplied slot. For example, if the
-    /// BN is unable to find a parent block.
+    /// Returns `NoBlockAvailable` if it is not possible to produce at the supplied slot. For
+    /// example, if the BN is unable to find a parent block. Presently the gRPC API does not state
+    /// a reason, so it is always `NoBlockReason::Unspecified`.
     fn produce_beacon_block(
         &self,
         slot: Slot,
         randao_reveal: &Signature,
-    ) -> Result<Option<BeaconBlock>, BeaconNodeError> {
+        graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError> {
         let mut req = ProduceBeaconBlockRequest::new();
         req.set_slot(slot.as_u64());
+        req.set_graffiti(graffiti.to_vec());
 
-        let reply = self
-            .client
-            .produce_beacon_block(&req)
-            .map_err(|err| BeaconNodeError::RemoteFailure(format!("{:?}", err)))?;
+        let reply = self.client.produce_beacon_block(&req).map_err(grpc_error)?;
 
         if reply.has_block() {
             let block = reply.get_block();
@@ -52,8 +104,13 @@ impl BeaconNode for BeaconBlockGrpcClient {
             let (randao_reveal, _) = Signature::ssz_decode(block.get_randao_reveal(), 0)
                 .map_err(|_| BeaconNodeError::DecodeFailure)?;
 
+            if block.get_graffiti().len() != 32 {
+                return Err(BeaconNodeError::DecodeFailure);
+            }
+            let graffiti = Hash256::from(block.get_graffiti());
+
             // TODO: this conversion is incomplete; fix it.
-            Ok(Some(BeaconBlock {
+            Ok(ProduceOutcome::Block(BeaconBlock {
                 slot: Slot::new(block.get_slot()),
                 parent_root: Hash256::zero(),
                 state_root: Hash256::zero(),
@@ -72,13 +129,29 @@ impl BeaconNode for BeaconBlockGrpcClient {
                     custody_responses: vec![],
                     deposits: vec![],
                     exits: vec![],
+                    graffiti,
                 },
             }))
         } else {
-            Ok(None)
+            Ok(ProduceOutcome::NoBlockAvailable(NoBlockReason::Unspecified))
         }
     }
 
+    /// Request a Beacon Node (BN) to produce a new minimal block at the supplied slot.
+    ///
+    /// Presently the gRPC API does not support minimal blocks, so this always fails.
+    fn produce_minimal_block(
+        &self,
+        _slot: Slot,
+        _randao_reveal: &Signature,
+        _graffiti: &[u8; 32],
+    ) -> Result<ProduceOutcome, BeaconNodeError> {
+        // TODO: add a minimal block endpoint to the gRPC API.
+        Err(BeaconNodeError::RemoteFailure(
+            "Minimal block production is not supported.".to_string(),
+        ))
+    }
+
     /// Request a Beacon Node (BN) to publish a block.
     ///
     /// Generally, this will be called after a `produce_beacon_block` call with a block that has
@@ -92,13 +165,11 @@ impl BeaconNode for BeaconBlockGrpcClient {
         grpc_block.set_block_root(vec![0]);
         grpc_block.set_randao_reveal(ssz_encode(&block.randao_reveal));
         grpc_block.set_signature(ssz_encode(&block.signature));
+        grpc_block.set_graffiti(block.body.graffiti[..].to_vec());
 
         req.set_block(grpc_block);
 
-        let reply = self
-            .client
-            .publish_beacon_block(&req)
-            .map_err(|err| BeaconNodeError::RemoteFailure(format!("{:?}", err)))?;
+        let reply = self.client.publish_beacon_block(&req).map_err(grpc_error)?;
 
         if reply.get_success() {
             Ok(PublishOutcome::ValidBlock)
@@ -107,4 +178,42 @@ impl BeaconNode for BeaconBlockGrpcClient {
             Ok(PublishOutcome::InvalidBlock("Publish failed".to_string()))
         }
     }
+
+    /// Request a Beacon Node (BN) to produce the attestation data for a shard at the supplied
+    /// slot.
+    ///
+    /// Presently the gRPC API does not support attestations, so this always fails.
+    fn produce_attestation(
+        &self,
+        _slot: Slot,
+        _shard: u64,
+    ) -> Result<Option<AttestationData>, BeaconNodeError> {
+        // TODO: add an attestation service to the gRPC API.
+        Err(BeaconNodeError::RemoteFailure(
+            "Attestation production is not supported.".to_string(),
+        ))
+    }
+
+    /// Request a Beacon Node (BN) to publish an attestation.
+    ///
+    /// Presently the gRPC API does not support attestations, so this always fails.
+    fn publish_attestation(
+        &self,
+        _free_attestation: FreeAttestation,
+    ) -> Result<AttestationPublishOutcome, BeaconNodeError> {
+        // TODO: add an attestation service to the gRPC API.
+        Err(BeaconNodeError::RemoteFailure(
+            "Attestation publishing is not supported.".to_string(),
+        ))
+    }
+}
+
+/// Maps a gRPC error to a `BeaconNodeError`, flagging an unreachable Beacon Node as retryable.
+fn grpc_error(err: GrpcError) -> BeaconNodeError {
+    match err {
+        GrpcError::RpcFailure(ref status) if status.status == RpcStatusCode::Unavailable => {
+            BeaconNodeError::Unavailable(format!("{:?}", err))
+        }
+        _ => BeaconNodeError::RemoteFailure(format!("{:?}", err)),
+    }
 }
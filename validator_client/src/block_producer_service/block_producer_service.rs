@@ -1,17 +1,26 @@
 use block_producer::{
-    BeaconNode, BlockProducer, DutiesReader, PollOutcome as BlockProducerPollOutcome, Signer,
+    BeaconNode, BlockProducer, DutiesReader, PollOutcome as BlockProducerPollOutcome,
+    PublishOutcome, Signer, SlashingProtection,
 };
-use slog::{error, info, warn, Logger};
+use slog::{crit, error, info, warn, Logger};
 use slot_clock::SlotClock;
 use std::time::Duration;
 
-pub struct BlockProducerService<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> {
-    pub block_producer: BlockProducer<T, U, V, W>,
+pub struct BlockProducerService<
+    T: SlotClock,
+    U: BeaconNode,
+    V: DutiesReader,
+    W: Signer,
+    X: SlashingProtection,
+> {
+    pub block_producer: BlockProducer<T, U, V, W, X>,
     pub poll_interval_millis: u64,
     pub log: Logger,
 }
 
-impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducerService<T, U, V, W> {
+impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer, X: SlashingProtection>
+    BlockProducerService<T, U, V, W, X>
+{
     /// Run a loop which polls the block producer each `poll_interval_millis` millseconds.
     ///
     /// Logs the results of the polls.
@@ -21,9 +30,27 @@ impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducerServi
                 Err(error) => {
                     error!(self.log, "Block producer poll error"; "error" => format!("{:?}", error))
                 }
-                Ok(BlockProducerPollOutcome::BlockProduced(slot)) => {
+                Ok(BlockProducerPollOutcome::FirstBlockProduced(
+                    slot,
+                    PublishOutcome::ValidBlock,
+                )) => {
+                    info!(self.log, "Produced first block since startup"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::BlockProduced(slot, PublishOutcome::ValidBlock)) => {
                     info!(self.log, "Produced block"; "slot" => slot)
                 }
+                Ok(BlockProducerPollOutcome::BlockProducedMinimal(
+                    slot,
+                    PublishOutcome::ValidBlock,
+                )) => warn!(self.log, "Produced minimal block after timeout"; "slot" => slot),
+                Ok(BlockProducerPollOutcome::FirstBlockProduced(slot, outcome))
+                | Ok(BlockProducerPollOutcome::BlockProduced(slot, outcome))
+                | Ok(BlockProducerPollOutcome::BlockProducedMinimal(slot, outcome)) => {
+                    warn!(
+                        self.log, "Produced block, flagged by Beacon Node";
+                        "slot" => slot, "outcome" => format!("{:?}", outcome)
+                    )
+                }
                 Ok(BlockProducerPollOutcome::SlashableBlockNotProduced(slot)) => {
                     warn!(self.log, "Slashable block was not signed"; "slot" => slot)
                 }
@@ -36,15 +63,57 @@ impl<T: SlotClock, U: BeaconNode, V: DutiesReader, W: Signer> BlockProducerServi
                 Ok(BlockProducerPollOutcome::SlotAlreadyProcessed(slot)) => {
                     warn!(self.log, "Attempted to re-process slot"; "slot" => slot)
                 }
+                Ok(BlockProducerPollOutcome::SlotMissed(slot)) => {
+                    warn!(self.log, "Slot missed"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::ProposalMissed(slot)) => {
+                    error!(self.log, "Block proposal missed"; "slot" => slot)
+                }
                 Ok(BlockProducerPollOutcome::BeaconNodeUnableToProduceBlock(slot)) => {
                     error!(self.log, "Beacon node unable to produce block"; "slot" => slot)
                 }
-                Ok(BlockProducerPollOutcome::SignerRejection(slot)) => {
-                    error!(self.log, "The cryptographic signer refused to sign the block"; "slot" => slot)
+                Ok(BlockProducerPollOutcome::BeaconNodeSyncing(slot)) => {
+                    warn!(self.log, "Beacon node is syncing, unable to produce block"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::BeaconNodeMissingParent(slot)) => {
+                    warn!(self.log, "Beacon node is missing the parent block"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::BeaconNodeReturnedMalformedBlock(slot)) => {
+                    error!(self.log, "Beacon node returned a malformed block"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::RandaoRevealMismatch(slot)) => {
+                    error!(self.log, "Beacon node returned a block with another RANDAO reveal"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::SignerLocked(slot)) => {
+                    warn!(self.log, "The cryptographic signer is locked, unable to sign the block"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::SignerUnknownKey(slot)) => {
+                    error!(self.log, "The cryptographic signer does not hold the validator key"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::SignerRejection(slot, reason)) => {
+                    error!(self.log, "The cryptographic signer refused to sign the block"; "slot" => slot, "reason" => reason)
+                }
+                Ok(BlockProducerPollOutcome::SignatureMismatch(slot)) => {
+                    crit!(self.log, "The cryptographic signer signed with another key, check its configuration"; "slot" => slot)
                 }
                 Ok(BlockProducerPollOutcome::ValidatorIsUnknown(slot)) => {
                     error!(self.log, "The Beacon Node does not recognise the validator"; "slot" => slot)
                 }
+                Ok(BlockProducerPollOutcome::ValidatorPendingActivation(slot)) => {
+                    info!(self.log, "The validator is pending activation"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::InsufficientPeers(slot)) => {
+                    warn!(self.log, "Beacon node has insufficient peers to produce block"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::QuietWindow(slot)) => {
+                    info!(self.log, "Block production suppressed by quiet window"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::Rebroadcast(slot)) => {
+                    info!(self.log, "Previously signed block rebroadcast"; "slot" => slot)
+                }
+                Ok(BlockProducerPollOutcome::PublishedBlockRejectedAsSlashable(slot)) => {
+                    crit!(self.log, "Published block rejected as slashable, is another validator client running?"; "slot" => slot)
+                }
             };
 
             std::thread::sleep(Duration::from_millis(self.poll_interval_millis));
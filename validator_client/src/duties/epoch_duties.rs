@@ -75,6 +75,16 @@ impl DutiesReader for EpochDutiesMap {
             .ok_or_else(|| DutiesReaderError::UnknownEpoch)?;
         Ok(duties.is_block_production_slot(slot))
     }
+
+    /// An `EpochDutiesMap` only ever stores the duties of a single validator.
+    fn managed_validator_count(&self) -> Result<usize, DutiesReaderError> {
+        Ok(1)
+    }
+
+    fn duties_epoch(&self) -> Result<Option<Epoch>, DutiesReaderError> {
+        let map = self.map.read().map_err(|_| DutiesReaderError::Poisoned)?;
+        Ok(map.keys().max().cloned())
+    }
 }
 
 // TODO: add tests.
@@ -1,7 +1,9 @@
 use self::block_producer_service::{BeaconBlockGrpcClient, BlockProducerService};
 use self::duties::{DutiesManager, DutiesManagerService, EpochDutiesMap};
 use crate::config::ClientConfig;
-use block_producer::{test_utils::LocalSigner, BlockProducer};
+use block_producer::{
+    test_utils::LocalSigner, BlockProducer, FileProcessLock, FileSlashingProtection, ProcessLock,
+};
 use bls::Keypair;
 use clap::{App, Arg};
 use grpcio::{ChannelBuilder, EnvBuilder};
@@ -95,6 +97,22 @@ fn main() {
         Arc::new(clock)
     };
 
+    // Records of signed blocks, shared between all validators so that slashable blocks are never
+    // produced (even across restarts).
+    let slashing_protection = {
+        let path = config.data_dir.join("slashing_protection");
+        info!(log, "Loading slashing protection records"; "path" => &path.to_str());
+        let records = FileSlashingProtection::open(path)
+            .expect("Unable to open slashing protection records.");
+        Arc::new(records)
+    };
+
+    let process_lock: Arc<dyn ProcessLock> = {
+        let path = config.data_dir.join("locks");
+        info!(log, "Using validator lock files"; "path" => &path.to_str());
+        Arc::new(FileProcessLock::new(path).expect("Unable to create validator lock directory."))
+    };
+
     let poll_interval_millis = spec.slot_duration * 1000 / 10; // 10% epoch time precision.
     info!(log, "Starting block producer service"; "polls_per_epoch" => spec.slot_duration * 1000 / poll_interval_millis);
 
@@ -145,9 +163,21 @@ fn main() {
             let slot_clock = slot_clock.clone();
             let log = log.clone();
             let client = Arc::new(BeaconBlockGrpcClient::new(beacon_block_grpc_client.clone()));
+            let slashing_protection = slashing_protection.clone();
+            let process_lock = process_lock.clone();
             thread::spawn(move || {
-                let block_producer =
-                    BlockProducer::new(spec, pubkey, duties_map, slot_clock, client, signer);
+                let block_producer = BlockProducer::new(
+                    spec,
+                    pubkey,
+                    duties_map,
+                    slot_clock,
+                    client,
+                    signer,
+                    slashing_protection,
+                    log.clone(),
+                )
+                .with_process_lock(process_lock)
+                .expect("Unable to lock validator, is another validator client running?");
                 let mut block_producer_service = BlockProducerService {
                     block_producer,
                     poll_interval_millis,